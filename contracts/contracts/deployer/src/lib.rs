@@ -4,7 +4,15 @@ use soroban_sdk::{
     contract, contractimpl, contracttype, map, vec, Address, Bytes, BytesN, Env, IntoVal, Map,
     Symbol, Val, Vec,
 };
-use sorosplits_utils::types::ShareDataKey;
+use sorosplits_utils::{
+    deploy::is_deployed_by,
+    types::{ProtocolFeeDataKey, ShareDataKey},
+};
+
+use crate::{errors::Error, storage::DeployerConfig};
+
+mod errors;
+mod storage;
 
 #[contract]
 pub struct Deployer;
@@ -36,6 +44,55 @@ pub struct NetworkArg {
 
 #[contractimpl]
 impl Deployer {
+    /// Initializes the deployer with the admin and the protocol fee.
+    ///
+    /// The admin has to be the account that deployed the deployer with the given salt,
+    /// so the initialization can't be front-run by another account.
+    ///
+    /// The protocol fee is passed to every contract deployed through the deployer.
+    /// If the deployer is not initialized, contracts are deployed without a protocol fee.
+    pub fn init_deployer(
+        env: Env,
+        admin: Address,
+        salt: BytesN<32>,
+        protocol_fee: ProtocolFeeDataKey,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+
+        if DeployerConfig::exists(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if !is_deployed_by(&env, &admin, &salt) {
+            return Err(Error::Unauthorized);
+        }
+        if !protocol_fee.is_valid() {
+            return Err(Error::InvalidProtocolFee);
+        }
+
+        DeployerConfig::init(&env, admin, protocol_fee);
+        Ok(())
+    }
+
+    /// Updates the protocol fee for the contracts deployed after the update.
+    ///
+    /// This has to be authorized by the deployer admin.
+    pub fn update_protocol_fee(env: Env, protocol_fee: ProtocolFeeDataKey) -> Result<(), Error> {
+        let config = DeployerConfig::get(&env)?;
+        config.require_admin();
+
+        if !protocol_fee.is_valid() {
+            return Err(Error::InvalidProtocolFee);
+        }
+
+        config.update_protocol_fee(&env, protocol_fee);
+        Ok(())
+    }
+
+    /// Returns the protocol fee passed to the deployed contracts.
+    pub fn get_protocol_fee(env: Env) -> Option<ProtocolFeeDataKey> {
+        DeployerConfig::get_protocol_fee(&env)
+    }
+
     /// Deploy the contract Wasm and after deployment invoke the init function
     /// of the contract with the given arguments.
     ///
@@ -43,6 +100,8 @@ impl Deployer {
     /// itself is used as deployer). This way the whole operation is atomic
     /// and it's not possible to frontrun the contract initialization.
     ///
+    /// The protocol fee is appended to the init arguments.
+    ///
     /// Returns the contract ID and result of the init function.
     pub fn deploy_splitter(
         env: Env,
@@ -53,6 +112,9 @@ impl Deployer {
     ) -> (Address, Val) {
         deployer.require_auth();
 
+        let mut init_args = init_args;
+        init_args.push_back(DeployerConfig::get_protocol_fee(&env).into_val(&env));
+
         // Deploy the contract using the uploaded Wasm with given hash.
         let deployed_address = env
             .deployer()
@@ -79,6 +141,9 @@ impl Deployer {
     ) -> (Address, Val) {
         deployer.require_auth();
 
+        let mut init_args = init_args;
+        init_args.push_back(DeployerConfig::get_protocol_fee(&env).into_val(&env));
+
         let deployed_address = env
             .deployer()
             .with_address(deployer, salt)
@@ -92,16 +157,22 @@ impl Deployer {
         (deployed_address, res)
     }
 
+    /// Deploys and initializes a network of diversifier contracts that share
+    /// to each other.
+    ///
+    /// Every diversifier in the network swaps through the given DEX adapter.
     pub fn deploy_network(
         env: Env,
         deployer: Address,
         wasm_hashes: Map<Symbol, BytesN<32>>,
+        dex_adapter_address: Address,
         args: Vec<NetworkArg>,
     ) -> Map<u32, Address> {
         deployer.require_auth();
 
         let splitter_wasm_hash = wasm_hashes.get(Symbol::new(&env, "splitter")).unwrap();
         let diversifier_wasm_hash = wasm_hashes.get(Symbol::new(&env, "diversifier")).unwrap();
+        let protocol_fee = DeployerConfig::get_protocol_fee(&env);
 
         // All the contract addresses
         let mut deployed_contracts: Map<u32, Address> = map![&env];
@@ -142,6 +213,8 @@ impl Deployer {
                 init_args.push_back(arg.salt.to_val());
                 init_args.push_back(arg.is_diversifier_active.into_val(&env));
                 init_args.push_back(common_splitter_args.to_val());
+                init_args.push_back(dex_adapter_address.to_val());
+                init_args.push_back(protocol_fee.into_val(&env));
 
                 let _: Val = env.invoke_contract(
                     &deployed_address,
@@ -0,0 +1,65 @@
+use soroban_sdk::{contracttype, Address, Env};
+use sorosplits_utils::{storage::bump_instance, types::ProtocolFeeDataKey};
+
+use crate::errors::Error;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DeployerDataKeys {
+    Config,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DeployerConfig {
+    pub admin: Address,
+    pub protocol_fee: ProtocolFeeDataKey,
+}
+impl DeployerConfig {
+    /// Initializes the config with the given admin address and protocol fee
+    pub fn init(e: &Env, admin: Address, protocol_fee: ProtocolFeeDataKey) {
+        bump_instance(e);
+        let key = DeployerDataKeys::Config;
+        let config = DeployerConfig {
+            admin,
+            protocol_fee,
+        };
+        e.storage().instance().set(&key, &config);
+    }
+
+    /// Returns the config
+    pub fn get(e: &Env) -> Result<DeployerConfig, Error> {
+        if !DeployerConfig::exists(e) {
+            return Err(Error::NotInitialized);
+        }
+        bump_instance(e);
+        let key = DeployerDataKeys::Config;
+        Ok(e.storage().instance().get(&key).unwrap())
+    }
+
+    /// Updates the protocol fee applied to the deployed contracts
+    pub fn update_protocol_fee(mut self, e: &Env, protocol_fee: ProtocolFeeDataKey) {
+        let key = DeployerDataKeys::Config;
+        self.protocol_fee = protocol_fee;
+        e.storage().instance().set(&key, &self);
+    }
+
+    /// Returns the protocol fee if the deployer is initialized
+    pub fn get_protocol_fee(e: &Env) -> Option<ProtocolFeeDataKey> {
+        match DeployerConfig::get(e) {
+            Ok(config) => Some(config.protocol_fee),
+            Err(_) => None,
+        }
+    }
+
+    /// Returns true if DeployerConfig exists in the storage
+    pub fn exists(e: &Env) -> bool {
+        let key = DeployerDataKeys::Config;
+        e.storage().instance().has(&key)
+    }
+
+    /// Validates the admin address
+    pub fn require_admin(&self) {
+        self.admin.require_auth()
+    }
+}
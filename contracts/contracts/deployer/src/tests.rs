@@ -2,7 +2,9 @@
 extern crate alloc;
 extern crate std;
 
-use crate::{Deployer, DeployerClient, NetworkArg, OutputContractData, SplitterData};
+use crate::{
+    errors::Error, Deployer, DeployerClient, NetworkArg, OutputContractData, SplitterData,
+};
 use alloc::vec;
 use soroban_sdk::{
     map,
@@ -14,7 +16,7 @@ use soroban_sdk::{
     },
     Address, Bytes, BytesN, Env, IntoVal, Symbol, Val, Vec,
 };
-use sorosplits_utils::types::ShareDataKey;
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
 
 // Splitter contract to be deployed
 mod splitter_contract {
@@ -23,7 +25,11 @@ mod splitter_contract {
     );
 }
 mod diversfier_contract {
-    use super::splitter_contract::ConfigDataKey;
+    use super::splitter_contract::{
+        ConfigDataKey, DistributionRoundDataKey, LifetimeTotalsDataKey, MerkleDistributionDataKey,
+        ScheduleDataKey, TokenBalanceDataKey, TokenInfoDataKey, VoucherDataKey,
+        WhitelistedTokenDataKey,
+    };
     use crate::tests::ContractError;
     soroban_sdk::contractimport!(
         file = "../../target/wasm32-unknown-unknown/release/sorosplits_diversifier.wasm"
@@ -126,6 +132,8 @@ fn test_deploy_from_address() {
 fn test_deploy_diversifier() {
     let env = Env::default();
     env.mock_all_auths();
+    // Reset the budget to unlimited
+    env.budget().reset_unlimited();
 
     let deployer_client = DeployerClient::new(&env, &env.register_contract(None, Deployer));
     let splitter_wasm_hash = env.deployer().upload_contract_wasm(splitter_contract::WASM);
@@ -146,12 +154,14 @@ fn test_deploy_diversifier() {
             len: 1000
         }).unwrap(),
     ).into_val(&env);
+    let dex_adapter_address = Address::generate(&env);
     let init_args: Vec<Val> = (
         deployer.clone(),
         splitter_wasm_hash.clone(),
         salt.clone(),
         true,
         splitter_init_args,
+        dex_adapter_address.clone(),
     )
         .into_val(&env);
 
@@ -194,6 +204,7 @@ fn test_deploy_diversifier() {
 
     let diversifier_config = client.get_diversifier_config();
     assert_eq!(diversifier_config.admin, deployer);
+    assert_eq!(diversifier_config.dex_adapter_address, dex_adapter_address);
     let config = client.get_config();
     assert_eq!(config.admin, contract_id);
     assert_eq!(
@@ -327,7 +338,13 @@ fn test_network_deploy() {
 
     let network_args = soroban_vec![&env, first_contract, second_contract, third_contract];
 
-    let deployed_contracts = deployer_client.deploy_network(&deployer, &wasm_hashes, &network_args);
+    let dex_adapter_address = Address::generate(&env);
+    let deployed_contracts = deployer_client.deploy_network(
+        &deployer,
+        &wasm_hashes,
+        &dex_adapter_address,
+        &network_args,
+    );
 
     // FIRST CONTRACT
     let client = splitter_contract::Client::new(&env, &deployed_contracts.get(1).unwrap());
@@ -343,6 +360,10 @@ fn test_network_deploy() {
 
     // SECOND DIVERSIFIER
     let client = diversfier_contract::Client::new(&env, &deployed_contracts.get(4).unwrap());
+    assert_eq!(
+        client.get_diversifier_config().dex_adapter_address,
+        dex_adapter_address
+    );
     let config = client.get_config();
     assert_eq!(
         config.name,
@@ -377,3 +398,114 @@ fn test_network_deploy() {
     assert_eq!(shares.get(2).unwrap().shareholder, shareholder1);
     assert_eq!(shares.get(3).unwrap().shareholder, shareholder3);
 }
+
+#[test]
+fn test_deploy_with_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let deployer_salt = BytesN::from_array(&env, &[1; 32]);
+    let deployer_client = register_deployer(&env, &admin, &deployer_salt);
+
+    let protocol_fee = ProtocolFeeDataKey {
+        recipient: Address::generate(&env),
+        fee: 100,
+    };
+    deployer_client.init_deployer(&admin, &deployer_salt, &protocol_fee);
+    assert_eq!(
+        deployer_client.get_protocol_fee(),
+        Some(protocol_fee.clone())
+    );
+
+    let wasm_hash = env.deployer().upload_contract_wasm(splitter_contract::WASM);
+    let deployer = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[0; 32]);
+    let init_fn_args: Vec<Val> = (
+        deployer.clone(),
+        Bytes::from_slice(&env, "Splitter Contract".as_bytes()),
+        soroban_vec![
+            &env,
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: Address::generate(&env),
+                share: 5000,
+            },
+        ],
+        true,
+    )
+        .into_val(&env);
+    let (contract_id, _) =
+        deployer_client.deploy_splitter(&deployer, &wasm_hash, &salt, &init_fn_args);
+
+    let client = splitter_contract::Client::new(&env, &contract_id);
+    assert_eq!(
+        client.get_config().protocol_fee,
+        splitter_contract::ProtocolFeeConfig::Enabled(splitter_contract::ProtocolFeeDataKey {
+            recipient: protocol_fee.recipient,
+            fee: 100,
+        })
+    );
+}
+
+#[test]
+fn test_init_deployer_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let deployer_salt = BytesN::from_array(&env, &[1; 32]);
+    let deployer_client = register_deployer(&env, &admin, &deployer_salt);
+
+    let protocol_fee = ProtocolFeeDataKey {
+        recipient: Address::generate(&env),
+        fee: 100,
+    };
+    assert!(deployer_client
+        .try_init_deployer(&admin, &deployer_salt, &protocol_fee)
+        .is_err());
+    assert_eq!(deployer_client.get_protocol_fee(), None);
+}
+
+#[test]
+fn test_init_deployer_not_deployed_by_admin() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let deployer_salt = BytesN::from_array(&env, &[1; 32]);
+    let deployer_client = register_deployer(&env, &admin, &deployer_salt);
+
+    let protocol_fee = ProtocolFeeDataKey {
+        recipient: Address::generate(&env),
+        fee: 100,
+    };
+
+    // Another account can't initialize the deployer before the admin
+    assert_eq!(
+        deployer_client.try_init_deployer(&Address::generate(&env), &deployer_salt, &protocol_fee),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(
+        deployer_client.try_init_deployer(
+            &admin,
+            &BytesN::from_array(&env, &[2; 32]),
+            &protocol_fee
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    deployer_client.init_deployer(&admin, &deployer_salt, &protocol_fee);
+    assert_eq!(deployer_client.get_protocol_fee(), Some(protocol_fee));
+}
+
+/// Registers the deployer at the address it gets when the admin deploys it with the salt
+fn register_deployer<'a>(env: &Env, admin: &Address, salt: &BytesN<32>) -> DeployerClient<'a> {
+    let deployer_address = env
+        .deployer()
+        .with_address(admin.clone(), salt.clone())
+        .deployed_address();
+    DeployerClient::new(env, &env.register_contract(&deployer_address, Deployer))
+}
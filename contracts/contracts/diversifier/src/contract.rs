@@ -1,33 +1,39 @@
+use soroban_fixed_point_math::FixedPoint;
 use soroban_sdk::{
-    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
-    contract, contractimpl, contractmeta, vec, Address, Bytes, BytesN, Env, Error, IntoVal, String,
-    Symbol, Val, Vec,
+    contract, contractimpl, contractmeta, map, vec, Address, Bytes, BytesN, Env, Error, IntoVal,
+    Map, Symbol, Val, Vec,
+};
+use sorosplits_utils::{
+    dex::DexAdapterClient,
+    oracle::{Asset, PriceOracleClient},
+    token::{get_token_client, validate_token},
 };
-use sorosplits_utils::token::{check_token, get_token_client};
 
 use crate::{
     errors::Error as ContractError,
-    storage::{config::DiversifierConfig, swaps::DiversifierWhitelistedSwapTokens},
+    storage::{
+        basket::{DiversifierBasket, DiversifierBasketTarget},
+        config::DiversifierConfig,
+        oracle::DiversifierPriceOracle,
+        pending::DiversifierPendingAmounts,
+        preferences::DiversifierPreferredTokens,
+        swaps::{
+            DiversifierIntermediateTokens, DiversifierWhitelistedSwapTokens,
+            MAX_INTERMEDIATE_TOKENS,
+        },
+    },
 };
 
-mod splitter_contract {
+pub(crate) mod splitter_contract {
     soroban_sdk::contractimport!(
         file = "../../target/wasm32-unknown-unknown/release/sorosplits_splitter.wasm",
     );
 }
-use splitter_contract::{ConfigDataKey, Contract as SplitterContract, ShareDataKey};
-
-mod soroswap_router {
-    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_router.optimized.wasm",);
-}
-use soroswap_router::Client as SoroswapRouterClient;
-mod soroswap_pair {
-    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_pair.optimized.wasm",);
-}
-use soroswap_pair::Client as SoroswapPairClient;
-
-// TESTNET
-const SOROSWAP_ROUTER_ADDRESS: &str = "CDGHOS7DDZ7DB24J7TMFDEAIR7LS7GLMT5J5KEZMUF6MSX5BFHCXQIB3";
+use splitter_contract::{
+    ConfigDataKey, Contract as SplitterContract, DistributionRoundDataKey, LifetimeTotalsDataKey,
+    MerkleDistributionDataKey, ProtocolFeeDataKey, ScheduleDataKey, ShareDataKey,
+    TokenBalanceDataKey, TokenInfoDataKey, VoucherDataKey, WhitelistedTokenDataKey,
+};
 
 contractmeta!(
     key = "desc",
@@ -47,6 +53,8 @@ pub trait DiversifierTrait {
     /// * `salt` - The salt to use for the deployment of the splitter contract.
     /// * `is_active` - Whether the diversifier should be active after initialization.
     /// * `splitter_init_args` - The arguments to pass to the init function of the splitter contract.
+    /// * `dex_adapter_address` - The address of the DEX adapter contract used for the swaps.
+    /// * `protocol_fee` - The optional protocol fee for the splitter contract.
     fn init_diversifier(
         env: Env,
         admin: Address,
@@ -54,10 +62,28 @@ pub trait DiversifierTrait {
         salt: BytesN<32>,
         is_active: bool,
         splitter_init_args: Vec<Val>,
+        dex_adapter_address: Address,
+        protocol_fee: Option<ProtocolFeeDataKey>,
+    ) -> Result<(), ContractError>;
+
+    /// Updates the address of the DEX adapter contract used for the swaps.
+    ///
+    /// The adapter has to implement the DEX adapter interface, this way the swaps
+    /// can be moved to another AMM without changing the diversifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `dex_adapter_address` - The address of the DEX adapter contract.
+    fn update_dex_adapter_address(
+        env: Env,
+        dex_adapter_address: Address,
     ) -> Result<(), ContractError>;
 
     /// Updates the whitelisted swap tokens for a token.
     ///
+    /// The swap tokens have to be whitelisted in the splitter contract, so the swapped
+    /// tokens can be distributed.
+    ///
     /// # Arguments
     ///
     /// * `token_address` - The address of the token to update the swap tokens for.
@@ -68,6 +94,28 @@ pub trait DiversifierTrait {
         swap_tokens: Vec<Address>,
     ) -> Result<(), ContractError>;
 
+    /// Updates the intermediate tokens used to find the best swap route.
+    ///
+    /// The list cannot contain duplicate tokens or more than 5 tokens.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - The list of intermediate tokens, e.g. XLM and USDC.
+    fn update_intermediate_tokens(env: Env, tokens: Vec<Address>) -> Result<(), ContractError>;
+
+    /// Updates the price oracle used to check the swap prices.
+    ///
+    /// Swaps are refused when the swap price deviates from the oracle price more than
+    /// the maximum deviation. Passing `None` removes the check.
+    ///
+    /// # Arguments
+    ///
+    /// * `price_oracle` - The SEP-40 price oracle, the maximum deviation in basis points and the maximum price age in seconds.
+    fn update_price_oracle(
+        env: Env,
+        price_oracle: Option<DiversifierPriceOracle>,
+    ) -> Result<(), ContractError>;
+
     /// Swaps tokens and distributes them to the shareholders.
     ///
     /// # Arguments
@@ -75,10 +123,149 @@ pub trait DiversifierTrait {
     /// * `token_address` - The address of the token to swap.
     /// * `swap_path` - The swap path to use for the swap. The first element is the token to swap, the last element is the token to receive.
     /// * `amount` - The amount of tokens to swap.
+    /// * `min_amount_out` - The minimum amount of tokens to receive from the swap.
+    /// * `deadline_ledger` - The last ledger sequence the swap can be executed in.
     fn swap_and_distribute_tokens(
         env: Env,
         swap_path: Vec<Address>,
         amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Swaps tokens through the route with the best output and distributes them to the shareholders.
+    ///
+    /// The direct route and the routes through each intermediate token are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in` - The address of the token to swap.
+    /// * `token_out` - The address of the token to receive.
+    /// * `amount` - The amount of tokens to swap.
+    /// * `min_amount_out` - The minimum amount of tokens to receive from the swap.
+    /// * `deadline_ledger` - The last ledger sequence the swap can be executed in.
+    fn swap_and_distribute_best(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Swaps tokens and keeps the output in the diversifier as pending until it is settled.
+    ///
+    /// Large swaps can be split into several swaps, each in its own transaction,
+    /// without exceeding the instruction limit with the distribution.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_path` - The swap path to use for the swap. The first element is the token to swap, the last element is the token to receive.
+    /// * `amount` - The amount of tokens to swap.
+    /// * `min_amount_out` - The minimum amount of tokens to receive from the swap.
+    /// * `deadline_ledger` - The last ledger sequence the swap can be executed in.
+    fn swap_to_pending(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Swaps tokens and transfers the output to the splitter contract without distributing it.
+    ///
+    /// The output is kept in the splitter as unused balance until `distribute_tokens` is called.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_path` - The swap path to use for the swap. The first element is the token to swap, the last element is the token to receive.
+    /// * `amount` - The amount of tokens to swap.
+    /// * `min_amount_out` - The minimum amount of tokens to receive from the swap.
+    /// * `deadline_ledger` - The last ledger sequence the swap can be executed in.
+    fn swap_to_splitter(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Transfers the pending amount of a token to the splitter contract and distributes it to the shareholders.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the swapped token to settle.
+    fn settle(env: Env, token_address: Address) -> Result<(), ContractError>;
+
+    /// Updates the target basket of a token.
+    ///
+    /// The weights of the targets must sum up to 10000. A target with the token itself
+    /// keeps that part of the amount as-is. The targets have to be whitelisted in the
+    /// splitter contract and cannot be duplicated, and the other targets have to be
+    /// whitelisted swap tokens of the token. An empty basket removes the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token to update the basket for.
+    /// * `targets` - The list of target tokens and their weights.
+    fn update_basket(
+        env: Env,
+        token_address: Address,
+        targets: Vec<DiversifierBasketTarget>,
+    ) -> Result<(), ContractError>;
+
+    /// Diversifies tokens into the target basket and distributes each resulting token to the shareholders.
+    ///
+    /// Every target part is swapped through the route with the best output.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token to diversify.
+    /// * `amount` - The amount of tokens to diversify.
+    /// * `min_amounts_out` - The minimum amount to receive for each target, in the order of the basket.
+    /// * `deadline_ledger` - The last ledger sequence the swaps can be executed in.
+    fn diversify_basket(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        min_amounts_out: Vec<i128>,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError>;
+
+    /// Updates the preferred payout token of a shareholder.
+    ///
+    /// Has to be authorized by the shareholder. The token has to be whitelisted in the
+    /// splitter contract. Passing `None` removes the preference.
+    ///
+    /// # Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder.
+    /// * `token_address` - The address of the token the shareholder wants to be paid in.
+    fn update_preferred_token(
+        env: Env,
+        shareholder: Address,
+        token_address: Option<Address>,
+    ) -> Result<(), ContractError>;
+
+    /// Distributes tokens to the shareholders in their preferred payout tokens.
+    ///
+    /// Only the portion of the shareholders with a preferred token is swapped, through the
+    /// route with the best output. Shareholders without a preference, or with a preferred
+    /// token that is not a whitelisted swap token, are paid in the distributed token.
+    /// Shareholders that opted out of their payout token are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute.
+    /// * `amount` - The amount of tokens to distribute.
+    /// * `min_amounts_out` - The minimum amount to receive for each preferred token that is swapped.
+    /// * `deadline_ledger` - The last ledger sequence the swaps can be executed in.
+    fn distribute_with_preferences(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        min_amounts_out: Map<Address, i128>,
+        deadline_ledger: u32,
     ) -> Result<(), ContractError>;
 
     /// Toggles the diversifier active state.
@@ -91,6 +278,71 @@ pub trait DiversifierTrait {
     /// * `DiversifierConfig` - The contract configuration
     fn get_diversifier_config(env: Env) -> Result<DiversifierConfig, ContractError>;
 
+    /// Quotes the amount of tokens the swap path outputs for the given amount.
+    ///
+    /// # Arguments
+    ///
+    /// * `swap_path` - The swap path to quote. The first element is the token to swap, the last element is the token to receive.
+    /// * `amount` - The amount of tokens to swap.
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The amount of tokens received at the end of the swap path
+    fn quote_swap(env: Env, swap_path: Vec<Address>, amount: i128) -> Result<i128, ContractError>;
+
+    /// Finds the route with the best output between two tokens.
+    ///
+    /// The direct route and the routes through each intermediate token are compared.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_in` - The address of the token to swap.
+    /// * `token_out` - The address of the token to receive.
+    /// * `amount` - The amount of tokens to swap.
+    ///
+    /// ## Returns
+    ///
+    /// * `(Vec<Address>, i128)` - The swap path of the best route and its output amount
+    fn quote_best_route(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount: i128,
+    ) -> Result<(Vec<Address>, i128), ContractError>;
+
+    /// Gets the target basket of a token.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the token to get the basket for.
+    fn get_basket(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Vec<DiversifierBasketTarget>, ContractError>;
+
+    /// Gets the preferred payout token of a shareholder.
+    ///
+    /// # Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder.
+    fn get_preferred_token(
+        env: Env,
+        shareholder: Address,
+    ) -> Result<Option<Address>, ContractError>;
+
+    /// Gets the price oracle used to check the swap prices.
+    fn get_price_oracle(env: Env) -> Result<Option<DiversifierPriceOracle>, ContractError>;
+
+    /// Gets the swapped amount of a token that is pending settlement.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_address` - The address of the swapped token.
+    fn get_pending_amount(env: Env, token_address: Address) -> Result<i128, ContractError>;
+
+    /// Lists the intermediate tokens used to find the best swap route.
+    fn list_intermediate_tokens(env: Env) -> Result<Vec<Address>, ContractError>;
+
     /// Lists the whitelisted swap tokens for a token.
     ///
     /// # Arguments
@@ -114,6 +366,8 @@ impl DiversifierTrait for Diversifier {
         salt: BytesN<32>,
         is_active: bool,
         splitter_init_args: Vec<Val>,
+        dex_adapter_address: Address,
+        protocol_fee: Option<ProtocolFeeDataKey>,
     ) -> Result<(), ContractError> {
         let args: Vec<Val> = vec![
             &env,
@@ -121,12 +375,31 @@ impl DiversifierTrait for Diversifier {
             splitter_init_args.get(0).unwrap(),
             splitter_init_args.get(1).unwrap(),
             splitter_init_args.get(2).unwrap(),
+            protocol_fee.into_val(&env),
         ];
 
         let splitter_address = env.deployer().with_current_contract(salt).deploy(wasm_hash);
         env.invoke_contract::<Val>(&splitter_address, &Symbol::new(&env, "init_splitter"), args);
 
-        DiversifierConfig::init(&env, admin, splitter_address, is_active);
+        DiversifierConfig::init(
+            &env,
+            admin,
+            splitter_address,
+            is_active,
+            dex_adapter_address,
+        );
+
+        Ok(())
+    }
+
+    fn update_dex_adapter_address(
+        env: Env,
+        dex_adapter_address: Address,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        config.update_dex_adapter_address(&env, dex_adapter_address);
 
         Ok(())
     }
@@ -136,13 +409,51 @@ impl DiversifierTrait for Diversifier {
         token_address: Address,
         swap_tokens: Vec<Address>,
     ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        check_splitter_whitelisted_tokens(&env, &config.splitter_address, &swap_tokens)?;
+
+        DiversifierWhitelistedSwapTokens::set(&env, token_address, swap_tokens);
+
+        Ok(())
+    }
+
+    fn update_intermediate_tokens(env: Env, tokens: Vec<Address>) -> Result<(), ContractError> {
         DiversifierConfig::get(&env)?.require_admin()?;
 
-        for token_address in swap_tokens.iter() {
-            check_token(&env, &token_address);
+        // Keep the number of quotes of the best route search bounded
+        if tokens.len() > MAX_INTERMEDIATE_TOKENS {
+            return Err(ContractError::IntermediateTokenLimitReached);
         }
 
-        DiversifierWhitelistedSwapTokens::set(&env, token_address, swap_tokens);
+        for (index, token_address) in tokens.iter().enumerate() {
+            validate_token(&env, &token_address)?;
+
+            // Check for duplicate tokens
+            if tokens.first_index_of(&token_address) != Some(index as u32) {
+                return Err(ContractError::DuplicateIntermediateToken);
+            }
+        }
+
+        DiversifierIntermediateTokens::set(&env, tokens);
+
+        Ok(())
+    }
+
+    fn update_price_oracle(
+        env: Env,
+        price_oracle: Option<DiversifierPriceOracle>,
+    ) -> Result<(), ContractError> {
+        DiversifierConfig::get(&env)?.require_admin()?;
+
+        if let Some(price_oracle) = &price_oracle {
+            if !price_oracle.is_valid() {
+                return Err(ContractError::InvalidPriceOracle);
+            }
+        }
+
+        DiversifierPriceOracle::set(&env, price_oracle);
 
         Ok(())
     }
@@ -151,139 +462,519 @@ impl DiversifierTrait for Diversifier {
         env: Env,
         swap_path: Vec<Address>,
         amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
     ) -> Result<(), ContractError> {
         let config = DiversifierConfig::get(&env)?;
         config.require_admin()?;
         config.require_diversifier_active()?;
 
-        // Swap path must have at least 2 elements
-        if swap_path.len() < 2 {
-            return Err(ContractError::InvalidSwapPath);
-        };
-
-        let token_address = swap_path.get(0).unwrap();
-        let swap_token_address = swap_path.last().unwrap();
-
-        if !DiversifierWhitelistedSwapTokens::is_swap_token_valid(
+        swap_and_distribute(
             &env,
-            &token_address,
-            &swap_token_address,
-        ) {
-            return Err(ContractError::InvalidSwapToken);
-        }
+            &config,
+            swap_path,
+            amount,
+            min_amount_out,
+            deadline_ledger,
+        )
+    }
 
-        let token_client = get_token_client(&env, &token_address);
+    fn swap_and_distribute_best(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_active()?;
 
-        let token_balance = token_client.balance(&env.current_contract_address());
-        if token_balance == 0 || amount > token_balance {
+        // Check the amount before quoting the routes
+        let token_balance = get_available_balance(&env, &token_in);
+        if amount <= 0 || amount > token_balance {
             return Err(ContractError::InsufficientTokenBalance);
         }
 
-        let splitter_client = splitter_contract::Client::new(&env, &config.splitter_address);
-        let soroswap_router_client = SoroswapRouterClient::new(
-            &env,
-            &Address::from_string(&String::from_str(&env, &SOROSWAP_ROUTER_ADDRESS)),
-        );
-
-        let pair_contract_address =
-            soroswap_router_client.router_pair_for(&token_address, &swap_token_address);
-        let soroswap_pair_client = SoroswapPairClient::new(&env, &pair_contract_address);
+        let dex_adapter_client = DexAdapterClient::new(&env, &config.dex_adapter_address);
+        let (swap_path, _) =
+            get_best_route(&env, &dex_adapter_client, &token_in, &token_out, amount)?;
 
-        // Get the reserves of the tokens
-        let (reserve_in, reserve_out) = soroswap_pair_client.get_reserves();
+        swap_and_distribute(
+            &env,
+            &config,
+            swap_path,
+            amount,
+            min_amount_out,
+            deadline_ledger,
+        )
+    }
 
-        // Get the maximum amount of tokens that will be received
-        let max_amount_out =
-            soroswap_router_client.router_get_amount_out(&amount, &reserve_in, &reserve_out);
+    fn swap_to_pending(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_active()?;
 
-        // Authorize token transfer for the current contract
-        // Without this tokens cannot be transferred from the current contract to the pair contract
-        env.authorize_as_current_contract(vec![
+        let amount_out = swap_tokens(
             &env,
-            InvokerContractAuthEntry::Contract(SubContractInvocation {
-                context: ContractContext {
-                    contract: token_address,
-                    fn_name: Symbol::new(&env, "transfer"),
-                    args: (
-                        env.current_contract_address(),
-                        pair_contract_address,
-                        amount,
-                    )
-                        .into_val(&env),
-                },
-                sub_invocations: vec![&env],
-            }),
-        ]);
-
-        // Swap the tokens
-        let res = soroswap_router_client.swap_exact_tokens_for_tokens(
-            &amount,
-            &max_amount_out,
+            &config,
             &swap_path,
-            &env.current_contract_address(),
-            &u64::MAX,
-        );
-        let total_swapped_amount = res.last().unwrap();
+            amount,
+            min_amount_out,
+            deadline_ledger,
+        )?;
+        DiversifierPendingAmounts::add(&env, swap_path.last().unwrap(), amount_out);
 
-        // TODO: TX will not pass if we uncomment these
-        // Max CPU instructions limit is 100M and this will exceed it
+        Ok(())
+    }
+
+    fn swap_to_splitter(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount: i128,
+        min_amount_out: i128,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_active()?;
 
-        // Transfer the swapped tokens to the splitter contract
-        get_token_client(&env, &swap_token_address).transfer(
+        let amount_out = swap_tokens(
+            &env,
+            &config,
+            &swap_path,
+            amount,
+            min_amount_out,
+            deadline_ledger,
+        )?;
+        get_token_client(&env, &swap_path.last().unwrap()).transfer(
             &env.current_contract_address(),
             &config.splitter_address,
-            &total_swapped_amount,
+            &amount_out,
         );
 
-        // Distribute the swapped tokens
-        splitter_client.distribute_tokens(&swap_token_address, &total_swapped_amount);
-
         Ok(())
     }
 
-    fn toggle_diversifier(env: Env) -> Result<(), ContractError> {
-        DiversifierConfig::get(&env)?.require_admin()?;
-        DiversifierConfig::toggle_diversifier_active(&env)?;
-        Ok(())
-    }
+    fn settle(env: Env, token_address: Address) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
 
-    fn get_diversifier_config(env: Env) -> Result<DiversifierConfig, ContractError> {
-        Ok(DiversifierConfig::get(&env).unwrap())
+        let pending_amount = DiversifierPendingAmounts::get(&env, &token_address);
+        if pending_amount == 0 {
+            return Err(ContractError::PendingAmountNotFound);
+        }
+        DiversifierPendingAmounts::remove(&env, token_address.clone());
+
+        distribute_with_splitter(&env, &config, &token_address, pending_amount, None);
+
+        Ok(())
     }
 
-    fn list_whitelisted_swap_tokens(
+    fn update_basket(
         env: Env,
         token_address: Address,
-    ) -> Result<Vec<Address>, ContractError> {
-        Ok(DiversifierWhitelistedSwapTokens::get(&env, &token_address))
-    }
-}
-
-#[contractimpl]
-impl SplitterContract for Diversifier {
-    fn init_splitter(
-        _env: Env,
-        _admin: Address,
-        _name: Bytes,
-        _shares: Vec<ShareDataKey>,
-        _updatable: bool,
-    ) -> Result<(), Error> {
-        Err(ContractError::NotAllowed.into())
-    }
-    fn update_whitelisted_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
+        targets: Vec<DiversifierBasketTarget>,
+    ) -> Result<(), ContractError> {
         let config = DiversifierConfig::get(&env)?;
         config.require_admin()?;
 
-        splitter_contract::Client::new(&env, &config.splitter_address)
-            .update_whitelisted_tokens(&tokens);
+        if targets.is_empty() {
+            DiversifierBasket::remove(&env, token_address);
+            return Ok(());
+        }
+
+        let mut target_tokens: Vec<Address> = Vec::new(&env);
+        let mut total_weight = 0;
+        for target in targets.iter() {
+            if target.weight <= 0 {
+                return Err(ContractError::InvalidBasket);
+            }
+            // Each token can only be a target once
+            if target_tokens.contains(&target.token) {
+                return Err(ContractError::InvalidBasket);
+            }
+            target_tokens.push_back(target.token);
+            total_weight += target.weight;
+        }
+        if total_weight != 10000 {
+            return Err(ContractError::InvalidBasket);
+        }
+        check_splitter_whitelisted_tokens(&env, &config.splitter_address, &target_tokens)?;
+
+        // The targets other than the input token have to be swappable from it
+        for target_token in target_tokens.iter() {
+            if target_token != token_address
+                && !DiversifierWhitelistedSwapTokens::is_swap_token_valid(
+                    &env,
+                    &token_address,
+                    &target_token,
+                )
+            {
+                return Err(ContractError::InvalidSwapToken);
+            }
+        }
+
+        DiversifierBasket::set(&env, token_address, targets);
+
         Ok(())
     }
-    fn transfer_tokens(
+
+    fn diversify_basket(
         env: Env,
         token_address: Address,
-        recipient: Address,
         amount: i128,
-    ) -> Result<(), Error> {
+        min_amounts_out: Vec<i128>,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_active()?;
+
+        if env.ledger().sequence() > deadline_ledger {
+            return Err(ContractError::SwapDeadlineExpired);
+        }
+
+        let targets = DiversifierBasket::get(&env, &token_address);
+        if targets.is_empty() {
+            return Err(ContractError::BasketNotFound);
+        }
+        if min_amounts_out.len() != targets.len() {
+            return Err(ContractError::InvalidBasket);
+        }
+
+        let token_balance = get_available_balance(&env, &token_address);
+        if amount <= 0 || amount > token_balance {
+            return Err(ContractError::InsufficientTokenBalance);
+        }
+
+        let dex_adapter_client = DexAdapterClient::new(&env, &config.dex_adapter_address);
+
+        let mut remaining_amount = amount;
+        for (i, target) in targets.iter().enumerate() {
+            // The last target receives the remainder so that the whole amount is used
+            let target_amount = if i as u32 == targets.len() - 1 {
+                remaining_amount
+            } else {
+                amount.fixed_mul_floor(target.weight, 10000).unwrap_or(0)
+            };
+            remaining_amount -= target_amount;
+            if target_amount == 0 {
+                continue;
+            }
+
+            let min_amount_out = min_amounts_out.get(i as u32).unwrap();
+            if target.token == token_address {
+                if target_amount < min_amount_out {
+                    return Err(ContractError::SlippageExceeded);
+                }
+                distribute_with_splitter(&env, &config, &token_address, target_amount, None);
+                continue;
+            }
+
+            let (swap_path, _) = get_best_route(
+                &env,
+                &dex_adapter_client,
+                &token_address,
+                &target.token,
+                target_amount,
+            )?;
+            swap_and_distribute(
+                &env,
+                &config,
+                swap_path,
+                target_amount,
+                min_amount_out,
+                deadline_ledger,
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn update_preferred_token(
+        env: Env,
+        shareholder: Address,
+        token_address: Option<Address>,
+    ) -> Result<(), ContractError> {
+        shareholder.require_auth();
+
+        let config = DiversifierConfig::get(&env)?;
+        if splitter_contract::Client::new(&env, &config.splitter_address)
+            .get_share(&shareholder)
+            .is_none()
+        {
+            return Err(ContractError::NotShareholder);
+        }
+
+        if let Some(token_address) = token_address.clone() {
+            check_splitter_whitelisted_tokens(
+                &env,
+                &config.splitter_address,
+                &vec![&env, token_address],
+            )?;
+        }
+
+        DiversifierPreferredTokens::set(&env, shareholder, token_address);
+
+        Ok(())
+    }
+
+    fn distribute_with_preferences(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        min_amounts_out: Map<Address, i128>,
+        deadline_ledger: u32,
+    ) -> Result<(), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_active()?;
+
+        if env.ledger().sequence() > deadline_ledger {
+            return Err(ContractError::SwapDeadlineExpired);
+        }
+
+        let token_balance = get_available_balance(&env, &token_address);
+        if amount <= 0 || amount > token_balance {
+            return Err(ContractError::InsufficientTokenBalance);
+        }
+
+        // Group the shareholders by their payout token
+        let splitter_client = splitter_contract::Client::new(&env, &config.splitter_address);
+        let mut payout_shareholders: Map<Address, Vec<Address>> = map![&env];
+        let mut payout_shares: Map<Address, i128> = map![&env];
+        let mut total_shares = 0;
+        for share in splitter_client.list_shares().iter() {
+            let payout_token = match DiversifierPreferredTokens::get(&env, &share.shareholder) {
+                Some(preferred_token)
+                    if DiversifierWhitelistedSwapTokens::is_swap_token_valid(
+                        &env,
+                        &token_address,
+                        &preferred_token,
+                    ) =>
+                {
+                    preferred_token
+                }
+                _ => token_address.clone(),
+            };
+            // The splitter skips the shareholders that opted out of their payout token,
+            // so their shares are left out to split the whole amount between the others
+            if splitter_client
+                .list_opt_outs(&share.shareholder)
+                .contains(&payout_token)
+            {
+                continue;
+            }
+
+            let mut shareholders = payout_shareholders
+                .get(payout_token.clone())
+                .unwrap_or(vec![&env]);
+            shareholders.push_back(share.shareholder);
+            payout_shareholders.set(payout_token.clone(), shareholders);
+            payout_shares.set(
+                payout_token.clone(),
+                payout_shares.get(payout_token).unwrap_or(0) + share.share,
+            );
+            total_shares += share.share;
+        }
+        if total_shares == 0 {
+            return Err(ContractError::NoOptedInShareholders);
+        }
+
+        let dex_adapter_client = DexAdapterClient::new(&env, &config.dex_adapter_address);
+
+        let mut remaining_amount = amount;
+        for (i, (payout_token, shareholders)) in payout_shareholders.iter().enumerate() {
+            // The last payout token receives the remainder so that the whole amount is used
+            let payout_amount = if i as u32 == payout_shareholders.len() - 1 {
+                remaining_amount
+            } else {
+                amount
+                    .fixed_mul_floor(
+                        payout_shares.get(payout_token.clone()).unwrap(),
+                        total_shares,
+                    )
+                    .unwrap_or(0)
+            };
+            remaining_amount -= payout_amount;
+            if payout_amount == 0 {
+                continue;
+            }
+
+            if payout_token == token_address {
+                distribute_with_splitter(
+                    &env,
+                    &config,
+                    &token_address,
+                    payout_amount,
+                    Some(shareholders),
+                );
+                continue;
+            }
+
+            let min_amount_out = match min_amounts_out.get(payout_token.clone()) {
+                Some(min_amount_out) => min_amount_out,
+                None => return Err(ContractError::MinAmountOutNotFound),
+            };
+            let (swap_path, _) = get_best_route(
+                &env,
+                &dex_adapter_client,
+                &token_address,
+                &payout_token,
+                payout_amount,
+            )?;
+            let swapped_amount = swap_tokens(
+                &env,
+                &config,
+                &swap_path,
+                payout_amount,
+                min_amount_out,
+                deadline_ledger,
+            )?;
+            distribute_with_splitter(
+                &env,
+                &config,
+                &payout_token,
+                swapped_amount,
+                Some(shareholders),
+            );
+        }
+
+        Ok(())
+    }
+
+    fn toggle_diversifier(env: Env) -> Result<(), ContractError> {
+        DiversifierConfig::get(&env)?.require_admin()?;
+        DiversifierConfig::toggle_diversifier_active(&env)?;
+        Ok(())
+    }
+
+    fn get_diversifier_config(env: Env) -> Result<DiversifierConfig, ContractError> {
+        Ok(DiversifierConfig::get(&env).unwrap())
+    }
+
+    fn quote_swap(env: Env, swap_path: Vec<Address>, amount: i128) -> Result<i128, ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        let dex_adapter_client = DexAdapterClient::new(&env, &config.dex_adapter_address);
+        let amount_out = get_swap_quote(&dex_adapter_client, &swap_path, amount)?;
+        Ok(amount_out)
+    }
+
+    fn quote_best_route(
+        env: Env,
+        token_in: Address,
+        token_out: Address,
+        amount: i128,
+    ) -> Result<(Vec<Address>, i128), ContractError> {
+        let config = DiversifierConfig::get(&env)?;
+        let dex_adapter_client = DexAdapterClient::new(&env, &config.dex_adapter_address);
+        get_best_route(&env, &dex_adapter_client, &token_in, &token_out, amount)
+    }
+
+    fn get_basket(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Vec<DiversifierBasketTarget>, ContractError> {
+        Ok(DiversifierBasket::get(&env, &token_address))
+    }
+
+    fn get_preferred_token(
+        env: Env,
+        shareholder: Address,
+    ) -> Result<Option<Address>, ContractError> {
+        Ok(DiversifierPreferredTokens::get(&env, &shareholder))
+    }
+
+    fn get_price_oracle(env: Env) -> Result<Option<DiversifierPriceOracle>, ContractError> {
+        Ok(DiversifierPriceOracle::get(&env))
+    }
+
+    fn get_pending_amount(env: Env, token_address: Address) -> Result<i128, ContractError> {
+        Ok(DiversifierPendingAmounts::get(&env, &token_address))
+    }
+
+    fn list_intermediate_tokens(env: Env) -> Result<Vec<Address>, ContractError> {
+        Ok(DiversifierIntermediateTokens::get(&env))
+    }
+
+    fn list_whitelisted_swap_tokens(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Vec<Address>, ContractError> {
+        Ok(DiversifierWhitelistedSwapTokens::get(&env, &token_address))
+    }
+}
+
+#[contractimpl]
+impl SplitterContract for Diversifier {
+    fn init_splitter(
+        _env: Env,
+        _admin: Address,
+        _name: Bytes,
+        _shares: Vec<ShareDataKey>,
+        _updatable: bool,
+        _protocol_fee: Option<ProtocolFeeDataKey>,
+    ) -> Result<(), Error> {
+        Err(ContractError::NotAllowed.into())
+    }
+    fn update_whitelisted_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .update_whitelisted_tokens(&tokens);
+        Ok(())
+    }
+    fn add_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .add_whitelisted_token(&token_address);
+        Ok(())
+    }
+    fn remove_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .remove_whitelisted_token(&token_address);
+        Ok(())
+    }
+    fn update_min_distribution(
+        env: Env,
+        token_address: Address,
+        min_distribution: i128,
+    ) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .update_min_distribution(&token_address, &min_distribution);
+        Ok(())
+    }
+    fn reconcile_allocations(env: Env, token_address: Address) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .reconcile_allocations(&token_address);
+        Ok(())
+    }
+    fn transfer_tokens(
+        env: Env,
+        token_address: Address,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
         let config = DiversifierConfig::get(&env)?;
         config.require_admin()?;
 
@@ -298,11 +989,78 @@ impl SplitterContract for Diversifier {
     fn distribute_tokens(env: Env, token_address: Address, amount: i128) -> Result<(), Error> {
         let config = DiversifierConfig::get(&env)?;
         config.require_admin()?;
+
+        // Tokens of an active diversifier are swapped before the distribution,
+        // only the balance already in the splitter is distributed, e.g. from `swap_to_splitter`
+        if !config.diversifier_active {
+            transfer_diversifier_balance_to_splitter(
+                &env,
+                &token_address,
+                &config.splitter_address,
+            );
+        }
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .distribute_tokens(&token_address, &amount);
+        Ok(())
+    }
+    fn distribute_to_shareholders(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        shareholders: Vec<Address>,
+    ) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
         config.require_diversifier_inactive()?;
 
         transfer_diversifier_balance_to_splitter(&env, &token_address, &config.splitter_address);
+        splitter_contract::Client::new(&env, &config.splitter_address).distribute_to_shareholders(
+            &token_address,
+            &amount,
+            &shareholders,
+        );
+        Ok(())
+    }
+    fn create_merkle_distribution(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        merkle_root: BytesN<32>,
+        expiry: u32,
+    ) -> Result<u32, Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+        config.require_diversifier_inactive()?;
+
+        transfer_diversifier_balance_to_splitter(&env, &token_address, &config.splitter_address);
+        Ok(
+            splitter_contract::Client::new(&env, &config.splitter_address)
+                .create_merkle_distribution(&token_address, &amount, &merkle_root, &expiry),
+        )
+    }
+    fn close_merkle_distribution(env: Env, distribution_id: u32) -> Result<i128, Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        Ok(
+            splitter_contract::Client::new(&env, &config.splitter_address)
+                .close_merkle_distribution(&distribution_id),
+        )
+    }
+    fn update_voucher_signer(env: Env, public_key: Option<BytesN<32>>) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
         splitter_contract::Client::new(&env, &config.splitter_address)
-            .distribute_tokens(&token_address, &amount);
+            .update_voucher_signer(&public_key);
+        Ok(())
+    }
+    fn update_auto_distribute(env: Env, auto_distribute: bool) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .update_auto_distribute(&auto_distribute);
         Ok(())
     }
     fn update_shares(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
@@ -336,6 +1094,40 @@ impl SplitterContract for Diversifier {
         );
         Ok(())
     }
+    fn claim_merkle_allocation(
+        env: Env,
+        distribution_id: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        recipient.require_auth();
+
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+
+        splitter_contract::Client::new(&env, &splitter_address).claim_merkle_allocation(
+            &distribution_id,
+            &index,
+            &recipient,
+            &amount,
+            &proof,
+        );
+        Ok(())
+    }
+    fn redeem_voucher(
+        env: Env,
+        voucher: VoucherDataKey,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        voucher.recipient.require_auth();
+
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+
+        splitter_contract::Client::new(&env, &splitter_address)
+            .redeem_voucher(&voucher, &signature);
+        Ok(())
+    }
     fn withdraw_external_allocation(
         env: Env,
         splitter_address: Address,
@@ -358,6 +1150,60 @@ impl SplitterContract for Diversifier {
         splitter_contract::Client::new(&env, &config.splitter_address).update_name(&name);
         Ok(())
     }
+    fn update_distribution_schedule(
+        env: Env,
+        token_address: Address,
+        interval: u32,
+        keeper_reward: i128,
+    ) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .update_distribution_schedule(&token_address, &interval, &keeper_reward);
+        Ok(())
+    }
+    fn remove_distribution_schedule(env: Env, token_address: Address) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+        config.require_admin()?;
+
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .remove_distribution_schedule(&token_address);
+        Ok(())
+    }
+    fn poke(env: Env, token_address: Address, keeper: Address) -> Result<(), Error> {
+        let config = DiversifierConfig::get(&env)?;
+
+        // Same as `distribute_tokens`, only the balance already in the splitter
+        // is distributed while the diversifier is active
+        if !config.diversifier_active {
+            transfer_diversifier_balance_to_splitter(
+                &env,
+                &token_address,
+                &config.splitter_address,
+            );
+        }
+        splitter_contract::Client::new(&env, &config.splitter_address)
+            .poke(&token_address, &keeper);
+        Ok(())
+    }
+    fn update_token_opt_out(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        opt_out: bool,
+    ) -> Result<(), Error> {
+        shareholder.require_auth();
+
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+
+        splitter_contract::Client::new(&env, &splitter_address).update_token_opt_out(
+            &shareholder,
+            &token_address,
+            &opt_out,
+        );
+        Ok(())
+    }
     fn get_unused_tokens(env: Env, token_address: Address) -> Result<i128, Error> {
         let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
         Ok(splitter_contract::Client::new(&env, &splitter_address)
@@ -371,28 +1217,408 @@ impl SplitterContract for Diversifier {
         let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
         Ok(splitter_contract::Client::new(&env, &splitter_address).list_shares())
     }
+    fn list_opt_outs(env: Env, shareholder: Address) -> Result<Vec<Address>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).list_opt_outs(&shareholder))
+    }
     fn get_config(env: Env) -> Result<ConfigDataKey, Error> {
         let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
         Ok(splitter_contract::Client::new(&env, &splitter_address).get_config())
     }
+    fn get_auto_distribute(env: Env) -> Result<bool, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).get_auto_distribute())
+    }
     fn get_allocation(env: Env, shareholder: Address, token: Address) -> Result<i128, Error> {
         let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
         Ok(splitter_contract::Client::new(&env, &splitter_address)
             .get_allocation(&shareholder, &token))
     }
-    fn list_whitelisted_tokens(env: Env) -> Result<Vec<Address>, Error> {
+    fn list_whitelisted_tokens(env: Env) -> Result<Vec<WhitelistedTokenDataKey>, Error> {
         let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
         Ok(splitter_contract::Client::new(&env, &splitter_address).list_whitelisted_tokens())
     }
+    fn get_token_balance(env: Env, token_address: Address) -> Result<TokenBalanceDataKey, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .get_token_balance(&token_address))
+    }
+    fn get_lifetime_totals(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .get_lifetime_totals(&shareholder, &token_address))
+    }
+    fn get_token_lifetime_totals(
+        env: Env,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .get_token_lifetime_totals(&token_address))
+    }
+    fn get_distribution(env: Env, round_id: u32) -> Result<DistributionRoundDataKey, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).get_distribution(&round_id))
+    }
+    fn list_distributions(
+        env: Env,
+        token_address: Address,
+        from: u32,
+        limit: u32,
+    ) -> Result<Vec<DistributionRoundDataKey>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(
+            splitter_contract::Client::new(&env, &splitter_address).list_distributions(
+                &token_address,
+                &from,
+                &limit,
+            ),
+        )
+    }
+    fn get_merkle_distribution(
+        env: Env,
+        distribution_id: u32,
+    ) -> Result<MerkleDistributionDataKey, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .get_merkle_distribution(&distribution_id))
+    }
+    fn is_merkle_claimed(env: Env, distribution_id: u32, index: u32) -> Result<bool, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .is_merkle_claimed(&distribution_id, &index))
+    }
+    fn get_voucher_signer(env: Env) -> Result<Option<BytesN<32>>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).get_voucher_signer())
+    }
+    fn is_voucher_redeemed(env: Env, nonce: u64) -> Result<bool, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).is_voucher_redeemed(&nonce))
+    }
+    fn list_whitelisted_token_info(env: Env) -> Result<Vec<TokenInfoDataKey>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).list_whitelisted_token_info())
+    }
+    fn list_tokens_with_allocations(env: Env) -> Result<Vec<(Address, i128)>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address).list_tokens_with_allocations())
+    }
+    fn get_distribution_schedule(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Option<ScheduleDataKey>, Error> {
+        let splitter_address = DiversifierConfig::get(&env)?.splitter_address;
+        Ok(splitter_contract::Client::new(&env, &splitter_address)
+            .get_distribution_schedule(&token_address))
+    }
+}
+
+/// Swaps the tokens through the swap path and distributes the output with the splitter
+fn swap_and_distribute(
+    env: &Env,
+    config: &DiversifierConfig,
+    swap_path: Vec<Address>,
+    amount: i128,
+    min_amount_out: i128,
+    deadline_ledger: u32,
+) -> Result<(), ContractError> {
+    let total_swapped_amount = swap_tokens(
+        env,
+        config,
+        &swap_path,
+        amount,
+        min_amount_out,
+        deadline_ledger,
+    )?;
+
+    // Large swaps together with the distribution can exceed the 100M CPU instructions limit,
+    // `swap_to_pending` and `settle` split them across transactions
+
+    distribute_with_splitter(
+        env,
+        config,
+        &swap_path.last().unwrap(),
+        total_swapped_amount,
+        None,
+    );
+
+    Ok(())
+}
+
+/// Swaps the tokens through the swap path and returns the amount of tokens received
+fn swap_tokens(
+    env: &Env,
+    config: &DiversifierConfig,
+    swap_path: &Vec<Address>,
+    amount: i128,
+    min_amount_out: i128,
+    deadline_ledger: u32,
+) -> Result<i128, ContractError> {
+    if env.ledger().sequence() > deadline_ledger {
+        return Err(ContractError::SwapDeadlineExpired);
+    }
+
+    // Swap path must have at least 2 elements
+    if swap_path.len() < 2 {
+        return Err(ContractError::InvalidSwapPath);
+    };
+
+    let token_address = swap_path.get(0).unwrap();
+    let swap_token_address = swap_path.last().unwrap();
+
+    if !DiversifierWhitelistedSwapTokens::is_swap_token_valid(
+        env,
+        &token_address,
+        &swap_token_address,
+    ) {
+        return Err(ContractError::InvalidSwapToken);
+    }
+
+    let token_client = get_token_client(env, &token_address);
+
+    let token_balance = get_available_balance(env, &token_address);
+    if token_balance <= 0 || amount > token_balance {
+        return Err(ContractError::InsufficientTokenBalance);
+    }
+
+    let dex_adapter_client = DexAdapterClient::new(env, &config.dex_adapter_address);
+
+    // Get the maximum amount of tokens that will be received across every hop
+    let max_amount_out = get_swap_quote(&dex_adapter_client, swap_path, amount)?;
+    if max_amount_out < min_amount_out {
+        return Err(ContractError::SlippageExceeded);
+    }
+    check_oracle_price(
+        env,
+        &config.splitter_address,
+        &token_address,
+        &swap_token_address,
+        amount,
+        max_amount_out,
+    )?;
+
+    // The received amount is measured from the balance instead of trusting the adapter
+    let swap_token_client = get_token_client(env, &swap_token_address);
+    let swap_token_balance = swap_token_client.balance(&env.current_contract_address());
+
+    // The adapter swaps the tokens transferred to it and sends the received tokens back
+    token_client.transfer(
+        &env.current_contract_address(),
+        &config.dex_adapter_address,
+        &amount,
+    );
+    dex_adapter_client.swap(
+        swap_path,
+        &amount,
+        &min_amount_out,
+        &env.current_contract_address(),
+    );
+
+    let amount_out =
+        swap_token_client.balance(&env.current_contract_address()) - swap_token_balance;
+    if amount_out < min_amount_out {
+        return Err(ContractError::SlippageExceeded);
+    }
+
+    Ok(amount_out)
+}
+
+/// Validates the swap price against the prices of the price oracle
+///
+/// Nothing is checked if there is no price oracle.
+fn check_oracle_price(
+    env: &Env,
+    splitter_address: &Address,
+    token_in: &Address,
+    token_out: &Address,
+    amount_in: i128,
+    amount_out: i128,
+) -> Result<(), ContractError> {
+    let price_oracle = match DiversifierPriceOracle::get(env) {
+        Some(price_oracle) => price_oracle,
+        None => return Ok(()),
+    };
+
+    let price_in = get_oracle_price(env, &price_oracle, token_in)?;
+    let price_out = get_oracle_price(env, &price_oracle, token_out)?;
+
+    // Amount of tokens that would be received at the oracle prices
+    let token_infos =
+        splitter_contract::Client::new(env, splitter_address).list_whitelisted_token_info();
+    let unit_in = 10i128.checked_pow(get_token_decimals(env, &token_infos, token_in));
+    let unit_out = 10i128.checked_pow(get_token_decimals(env, &token_infos, token_out));
+    let expected_amount_out = unit_in.zip(unit_out).and_then(|(unit_in, unit_out)| {
+        amount_in
+            .fixed_mul_floor(price_in, unit_in)
+            .and_then(|value| value.fixed_mul_floor(unit_out, price_out))
+    });
+
+    let deviation = expected_amount_out.and_then(|expected_amount_out| {
+        (amount_out - expected_amount_out)
+            .abs()
+            .fixed_mul_ceil(10000, expected_amount_out)
+    });
+    match deviation {
+        Some(deviation) if deviation <= price_oracle.max_deviation => Ok(()),
+        _ => Err(ContractError::PriceDeviationExceeded),
+    }
+}
+
+/// Returns the decimals of the token from the token info cached by the splitter contract
+///
+/// The token is only called for tokens that aren't whitelisted in the splitter.
+fn get_token_decimals(env: &Env, token_infos: &Vec<TokenInfoDataKey>, token: &Address) -> u32 {
+    token_infos
+        .iter()
+        .find(|token_info| token_info.token == *token)
+        .map(|token_info| token_info.decimals)
+        .unwrap_or_else(|| get_token_client(env, token).decimals())
+}
+
+/// Returns the latest price of the token from the price oracle
+///
+/// Prices older than the maximum price age are not used.
+fn get_oracle_price(
+    env: &Env,
+    price_oracle: &DiversifierPriceOracle,
+    token_address: &Address,
+) -> Result<i128, ContractError> {
+    let oracle_client = PriceOracleClient::new(env, &price_oracle.oracle_address);
+    match oracle_client.lastprice(&Asset::Stellar(token_address.clone())) {
+        Some(price_data)
+            if price_data.price > 0
+                && price_data
+                    .timestamp
+                    .saturating_add(price_oracle.max_price_age)
+                    >= env.ledger().timestamp() =>
+        {
+            Ok(price_data.price)
+        }
+        _ => Err(ContractError::OraclePriceNotFound),
+    }
+}
+
+/// Transfers the tokens to the splitter contract and distributes them to the shareholders
+///
+/// If shareholders are given, the tokens are distributed to only them.
+fn distribute_with_splitter(
+    env: &Env,
+    config: &DiversifierConfig,
+    token_address: &Address,
+    amount: i128,
+    shareholders: Option<Vec<Address>>,
+) {
+    get_token_client(env, token_address).transfer(
+        &env.current_contract_address(),
+        &config.splitter_address,
+        &amount,
+    );
+    let splitter_client = splitter_contract::Client::new(env, &config.splitter_address);
+    match shareholders {
+        Some(shareholders) => {
+            splitter_client.distribute_to_shareholders(token_address, &amount, &shareholders)
+        }
+        None => splitter_client.distribute_tokens(token_address, &amount),
+    }
+}
+
+/// Returns the amount of tokens received at the end of the swap path
+///
+/// Every hop of the swap path must be swappable through the DEX adapter.
+fn get_swap_quote(
+    dex_adapter_client: &DexAdapterClient,
+    swap_path: &Vec<Address>,
+    amount: i128,
+) -> Result<i128, ContractError> {
+    if swap_path.len() < 2 {
+        return Err(ContractError::InvalidSwapPath);
+    }
+    for i in 1..swap_path.len() {
+        if swap_path.get(i - 1) == swap_path.get(i) {
+            return Err(ContractError::InvalidSwapPath);
+        }
+    }
+
+    dex_adapter_client
+        .quote(swap_path, &amount)
+        .ok_or(ContractError::SwapPairNotFound)
+}
+
+/// Returns the swap path with the best output between the tokens and its output amount
+///
+/// The candidates are the direct route and the routes through each intermediate token.
+/// Routes with a missing pair are skipped.
+fn get_best_route(
+    env: &Env,
+    dex_adapter_client: &DexAdapterClient,
+    token_in: &Address,
+    token_out: &Address,
+    amount: i128,
+) -> Result<(Vec<Address>, i128), ContractError> {
+    let mut candidates: Vec<Vec<Address>> =
+        vec![env, vec![env, token_in.clone(), token_out.clone()]];
+    for token in DiversifierIntermediateTokens::get(env).iter() {
+        if token != *token_in && token != *token_out {
+            candidates.push_back(vec![env, token_in.clone(), token, token_out.clone()]);
+        }
+    }
+
+    let mut best_route: Option<(Vec<Address>, i128)> = None;
+    for swap_path in candidates.iter() {
+        let amount_out = match get_swap_quote(dex_adapter_client, &swap_path, amount) {
+            Ok(amount_out) => amount_out,
+            Err(_) => continue,
+        };
+        match best_route {
+            Some((_, best_amount_out)) if best_amount_out >= amount_out => {}
+            _ => best_route = Some((swap_path, amount_out)),
+        }
+    }
+
+    best_route.ok_or(ContractError::RouteNotFound)
+}
+
+/// Checks that the tokens are whitelisted in the splitter contract.
+///
+/// The splitter validates the tokens and caches their metadata when they are whitelisted,
+/// so the tokens themselves don't need to be called again.
+fn check_splitter_whitelisted_tokens(
+    env: &Env,
+    splitter_address: &Address,
+    tokens: &Vec<Address>,
+) -> Result<(), ContractError> {
+    let token_infos =
+        splitter_contract::Client::new(env, splitter_address).list_whitelisted_token_info();
+    for token in tokens.iter() {
+        if !token_infos
+            .iter()
+            .any(|token_info| token_info.token == token)
+        {
+            return Err(ContractError::TokenNotWhitelisted);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the balance of the token without the pending amount kept for its settlement
+fn get_available_balance(env: &Env, token_address: &Address) -> i128 {
+    get_token_client(env, token_address).balance(&env.current_contract_address())
+        - DiversifierPendingAmounts::get(env, token_address)
 }
 
+/// Transfers the balance of the token to the splitter contract, except the pending amount
 fn transfer_diversifier_balance_to_splitter(
     env: &Env,
     token_address: &Address,
     splitter_address: &Address,
 ) {
     let token_client = get_token_client(&env, &token_address);
-    let token_balance = token_client.balance(&env.current_contract_address());
+    let token_balance = get_available_balance(env, token_address);
     if token_balance > 0 {
         token_client.transfer(
             &env.current_contract_address(),
@@ -1,4 +1,5 @@
 use soroban_sdk::contracterror;
+use sorosplits_utils::token::TokenError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -10,4 +11,27 @@ pub enum Error {
     InvalidSwapPath = 204,
     InvalidSwapToken = 205,
     InsufficientTokenBalance = 206,
+    InvalidToken = 207,
+    SwapDeadlineExpired = 208,
+    SlippageExceeded = 209,
+    SwapPairNotFound = 210,
+    RouteNotFound = 211,
+    InvalidBasket = 212,
+    BasketNotFound = 213,
+    NotShareholder = 214,
+    MinAmountOutNotFound = 215,
+    InvalidPriceOracle = 216,
+    OraclePriceNotFound = 217,
+    PriceDeviationExceeded = 218,
+    PendingAmountNotFound = 219,
+    TokenNotWhitelisted = 220,
+    IntermediateTokenLimitReached = 221,
+    DuplicateIntermediateToken = 222,
+    NoOptedInShareholders = 223,
+}
+
+impl From<TokenError> for Error {
+    fn from(_: TokenError) -> Self {
+        Error::InvalidToken
+    }
 }
@@ -3,3 +3,6 @@
 mod contract;
 mod errors;
 mod storage;
+
+#[cfg(test)]
+mod tests;
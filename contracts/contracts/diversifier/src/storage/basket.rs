@@ -0,0 +1,41 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use sorosplits_utils::storage::bump_persistent;
+
+use super::DiversifierDataKeys;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DiversifierBasketTarget {
+    /// Token to diversify into. The input token itself is kept as-is
+    pub token: Address,
+    /// Weight of the target out of 10000
+    pub weight: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversifierBasket {}
+impl DiversifierBasket {
+    pub fn set(e: &Env, token_address: Address, targets: Vec<DiversifierBasketTarget>) {
+        let key = DiversifierDataKeys::Basket(token_address);
+        e.storage().persistent().set(&key, &targets);
+        bump_persistent(e, &key);
+    }
+
+    pub fn remove(e: &Env, token_address: Address) {
+        let key = DiversifierDataKeys::Basket(token_address);
+        e.storage().persistent().remove(&key);
+    }
+
+    pub fn get(e: &Env, token_address: &Address) -> Vec<DiversifierBasketTarget> {
+        let key = DiversifierDataKeys::Basket(token_address.clone());
+        let res: Option<Vec<DiversifierBasketTarget>> = e.storage().persistent().get(&key);
+        match res {
+            Some(targets) => {
+                bump_persistent(e, &key);
+                targets
+            }
+            None => Vec::new(e),
+        }
+    }
+}
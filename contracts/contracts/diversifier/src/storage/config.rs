@@ -10,14 +10,23 @@ pub struct DiversifierConfig {
     pub admin: Address,
     pub splitter_address: Address,
     pub diversifier_active: bool,
+    /// Address of the DEX adapter contract used for the swaps
+    pub dex_adapter_address: Address,
 }
 impl DiversifierConfig {
-    pub fn init(e: &Env, admin: Address, splitter_address: Address, diversifier_active: bool) {
+    pub fn init(
+        e: &Env,
+        admin: Address,
+        splitter_address: Address,
+        diversifier_active: bool,
+        dex_adapter_address: Address,
+    ) {
         let key = DiversifierDataKeys::Config;
         let config = DiversifierConfig {
             admin,
             splitter_address,
             diversifier_active,
+            dex_adapter_address,
         };
         e.storage().instance().set(&key, &config);
     }
@@ -39,6 +48,13 @@ impl DiversifierConfig {
         Ok(())
     }
 
+    pub fn update_dex_adapter_address(mut self, e: &Env, dex_adapter_address: Address) {
+        self.dex_adapter_address = dex_adapter_address;
+        e.storage()
+            .instance()
+            .set(&DiversifierDataKeys::Config, &self);
+    }
+
     pub fn exists(e: &Env) -> bool {
         let key = DiversifierDataKeys::Config;
         e.storage().instance().has(&key)
@@ -1,4 +1,8 @@
+pub mod basket;
 pub mod config;
+pub mod oracle;
+pub mod pending;
+pub mod preferences;
 pub mod swaps;
 
 use soroban_sdk::{contracttype, Address};
@@ -8,4 +12,9 @@ use soroban_sdk::{contracttype, Address};
 pub enum DiversifierDataKeys {
     Config,
     SwapTokens(Address),
+    IntermediateTokens,
+    Basket(Address),
+    PreferredToken(Address),
+    PriceOracle,
+    PendingAmount(Address),
 }
@@ -0,0 +1,33 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use super::DiversifierDataKeys;
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DiversifierPriceOracle {
+    /// Address of the SEP-40 price oracle
+    pub oracle_address: Address,
+    /// Maximum deviation of the swap price from the oracle price in basis points
+    pub max_deviation: i128,
+    /// Maximum age of the oracle prices in seconds
+    pub max_price_age: u64,
+}
+impl DiversifierPriceOracle {
+    pub fn set(e: &Env, price_oracle: Option<DiversifierPriceOracle>) {
+        let key = DiversifierDataKeys::PriceOracle;
+        match price_oracle {
+            Some(price_oracle) => e.storage().instance().set(&key, &price_oracle),
+            None => e.storage().instance().remove(&key),
+        }
+    }
+
+    pub fn get(e: &Env) -> Option<DiversifierPriceOracle> {
+        let key = DiversifierDataKeys::PriceOracle;
+        e.storage().instance().get(&key)
+    }
+
+    /// Returns true if the deviation is between 0 and 10000 and the price age is positive
+    pub fn is_valid(&self) -> bool {
+        (0..=10000).contains(&self.max_deviation) && self.max_price_age > 0
+    }
+}
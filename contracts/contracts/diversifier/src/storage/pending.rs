@@ -0,0 +1,37 @@
+use soroban_sdk::{Address, Env};
+
+use sorosplits_utils::storage::bump_persistent;
+
+use super::DiversifierDataKeys;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversifierPendingAmounts {}
+impl DiversifierPendingAmounts {
+    /// Adds the swapped amount to the pending amount of the token
+    pub fn add(e: &Env, token_address: Address, amount: i128) {
+        let pending_amount = DiversifierPendingAmounts::get(e, &token_address);
+        let key = DiversifierDataKeys::PendingAmount(token_address);
+        e.storage()
+            .persistent()
+            .set(&key, &(pending_amount + amount));
+        bump_persistent(e, &key);
+    }
+
+    pub fn remove(e: &Env, token_address: Address) {
+        let key = DiversifierDataKeys::PendingAmount(token_address);
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns the swapped amount of the token that is not distributed yet
+    pub fn get(e: &Env, token_address: &Address) -> i128 {
+        let key = DiversifierDataKeys::PendingAmount(token_address.clone());
+        let res: Option<i128> = e.storage().persistent().get(&key);
+        match res {
+            Some(amount) => {
+                bump_persistent(e, &key);
+                amount
+            }
+            None => 0,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use soroban_sdk::{Address, Env};
+
+use sorosplits_utils::storage::bump_persistent;
+
+use super::DiversifierDataKeys;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversifierPreferredTokens {}
+impl DiversifierPreferredTokens {
+    pub fn set(e: &Env, shareholder: Address, token_address: Option<Address>) {
+        let key = DiversifierDataKeys::PreferredToken(shareholder);
+        match token_address {
+            Some(token_address) => {
+                e.storage().persistent().set(&key, &token_address);
+                bump_persistent(e, &key);
+            }
+            None => e.storage().persistent().remove(&key),
+        }
+    }
+
+    pub fn get(e: &Env, shareholder: &Address) -> Option<Address> {
+        let key = DiversifierDataKeys::PreferredToken(shareholder.clone());
+        let res: Option<Address> = e.storage().persistent().get(&key);
+        if res.is_some() {
+            bump_persistent(e, &key);
+        }
+        res
+    }
+}
@@ -4,6 +4,9 @@ use sorosplits_utils::storage::bump_persistent;
 
 use super::DiversifierDataKeys;
 
+/// Maximum number of intermediate tokens, each one adds a quote to the best route search
+pub const MAX_INTERMEDIATE_TOKENS: u32 = 5;
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct DiversifierWhitelistedSwapTokens {}
 impl DiversifierWhitelistedSwapTokens {
@@ -33,3 +36,17 @@ impl DiversifierWhitelistedSwapTokens {
         swap_tokens.contains(swap_token_address)
     }
 }
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct DiversifierIntermediateTokens {}
+impl DiversifierIntermediateTokens {
+    pub fn set(e: &Env, tokens: Vec<Address>) {
+        let key = DiversifierDataKeys::IntermediateTokens;
+        e.storage().instance().set(&key, &tokens);
+    }
+
+    pub fn get(e: &Env) -> Vec<Address> {
+        let key = DiversifierDataKeys::IntermediateTokens;
+        e.storage().instance().get(&key).unwrap_or(Vec::new(e))
+    }
+}
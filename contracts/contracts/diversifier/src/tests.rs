@@ -0,0 +1,13 @@
+mod distribute_with_preferences;
+mod diversify_basket;
+mod helpers;
+mod quote_best_route;
+mod quote_swap;
+mod settle;
+mod swap_and_distribute_tokens;
+mod swap_to_splitter;
+mod update_dex_adapter_address;
+mod update_intermediate_tokens;
+mod update_preferred_token;
+mod update_price_oracle;
+mod update_whitelisted_swap_tokens;
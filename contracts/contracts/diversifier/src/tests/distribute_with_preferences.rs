@@ -0,0 +1,164 @@
+use soroban_sdk::{map, testutils::Address as _, vec, Address, Env};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, _, usdc_address) = create_token(&env, &admin);
+    let (_, _, xlm_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &usdc_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_2 = shares.get(1).unwrap().shareholder;
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![
+        &env,
+        token_address.clone(),
+        usdc_address.clone(),
+        xlm_address.clone(),
+    ]);
+    diversifier.update_whitelisted_swap_tokens(&token_address, &vec![&env, usdc_address.clone()]);
+
+    diversifier.update_preferred_token(&shareholder_1, &Some(usdc_address.clone()));
+    // XLM is not a whitelisted swap token so the shareholder is paid in the distributed token
+    diversifier.update_preferred_token(&shareholder_2, &Some(xlm_address.clone()));
+
+    token_admin_client.mint(&diversifier_address, &1000);
+
+    assert_eq!(
+        diversifier.try_distribute_with_preferences(&token_address, &1000, &map![&env], &0),
+        Err(Ok(Error::MinAmountOutNotFound))
+    );
+
+    // Only the portion of the first shareholder is swapped
+    let usdc_amount_out = diversifier.quote_swap(
+        &vec![&env, token_address.clone(), usdc_address.clone()],
+        &805,
+    );
+    diversifier.distribute_with_preferences(
+        &token_address,
+        &1000,
+        &map![&env, (usdc_address.clone(), usdc_amount_out)],
+        &0,
+    );
+
+    assert_eq!(
+        diversifier.get_allocation(&shareholder_1, &usdc_address),
+        usdc_amount_out
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shareholder_1, &token_address),
+        0
+    );
+    assert_eq!(diversifier.get_allocation(&shareholder_2, &usdc_address), 0);
+    assert_eq!(
+        diversifier.get_allocation(&shareholder_2, &token_address),
+        195
+    );
+}
+
+#[test]
+fn test_opted_out_shareholders() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, _, usdc_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &usdc_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_2 = shares.get(1).unwrap().shareholder;
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, token_address.clone(), usdc_address.clone()]);
+    diversifier.update_whitelisted_swap_tokens(&token_address, &vec![&env, usdc_address.clone()]);
+
+    // Every member of the USDC group opted out of USDC
+    diversifier.update_preferred_token(&shareholder_1, &Some(usdc_address.clone()));
+    diversifier.update_token_opt_out(&shareholder_1, &usdc_address, &true);
+
+    token_admin_client.mint(&diversifier_address, &2000);
+
+    // The whole amount goes to the remaining shareholder without a swap
+    diversifier.distribute_with_preferences(&token_address, &1000, &map![&env], &0);
+    assert_eq!(diversifier.get_allocation(&shareholder_1, &usdc_address), 0);
+    assert_eq!(
+        diversifier.get_allocation(&shareholder_1, &token_address),
+        0
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shareholder_2, &token_address),
+        1000
+    );
+
+    // Nobody is left to distribute to
+    diversifier.update_token_opt_out(&shareholder_2, &token_address, &true);
+    assert_eq!(
+        diversifier.try_distribute_with_preferences(&token_address, &1000, &map![&env], &0),
+        Err(Ok(Error::NoOptedInShareholders))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_distribute_with_preferences(&token_address, &1000, &map![&env], &0)
+        .is_err());
+}
@@ -0,0 +1,273 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    storage::basket::DiversifierBasketTarget,
+    tests::helpers::{
+        create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, _, usdc_address) = create_token(&env, &admin);
+    let (_, _, xlm_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &usdc_address,
+        1_000_000,
+        1_000_000,
+    );
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &xlm_address,
+        1_000_000,
+        2_000_000,
+    );
+
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &create_soroswap_adapter(&env, &router),
+    );
+    diversifier.update_whitelisted_tokens(&vec![
+        &env,
+        token_address.clone(),
+        usdc_address.clone(),
+        xlm_address.clone(),
+    ]);
+    diversifier.update_whitelisted_swap_tokens(
+        &token_address,
+        &vec![&env, usdc_address.clone(), xlm_address.clone()],
+    );
+
+    let basket = vec![
+        &env,
+        DiversifierBasketTarget {
+            token: usdc_address.clone(),
+            weight: 5000,
+        },
+        DiversifierBasketTarget {
+            token: xlm_address.clone(),
+            weight: 3000,
+        },
+        DiversifierBasketTarget {
+            token: token_address.clone(),
+            weight: 2000,
+        },
+    ];
+    diversifier.update_basket(&token_address, &basket);
+    assert_eq!(diversifier.get_basket(&token_address), basket);
+
+    token_admin_client.mint(&diversifier_address, &1000);
+
+    let usdc_amount_out = diversifier.quote_swap(
+        &vec![&env, token_address.clone(), usdc_address.clone()],
+        &500,
+    );
+    let xlm_amount_out = diversifier.quote_swap(
+        &vec![&env, token_address.clone(), xlm_address.clone()],
+        &300,
+    );
+
+    assert_eq!(
+        diversifier.try_diversify_basket(&token_address, &1000, &vec![&env, 0, 0], &0),
+        Err(Ok(Error::InvalidBasket))
+    );
+    assert_eq!(
+        diversifier.try_diversify_basket(
+            &token_address,
+            &1000,
+            &vec![&env, usdc_amount_out + 1, xlm_amount_out, 200],
+            &0
+        ),
+        Err(Ok(Error::SlippageExceeded))
+    );
+
+    diversifier.diversify_basket(
+        &token_address,
+        &1000,
+        &vec![&env, usdc_amount_out, xlm_amount_out, 200],
+        &0,
+    );
+
+    assert_eq!(
+        diversifier.get_token_balance(&usdc_address).balance,
+        usdc_amount_out
+    );
+    assert_eq!(
+        diversifier.get_token_balance(&xlm_address).balance,
+        xlm_amount_out
+    );
+    assert_eq!(diversifier.get_token_balance(&token_address).balance, 200);
+}
+
+#[test]
+fn test_invalid_basket() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let (_, _, usdc_address) = create_token(&env, &admin);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    // Targets have to be whitelisted in the splitter
+    assert_eq!(
+        diversifier.try_update_basket(
+            &token_address,
+            &vec![
+                &env,
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 10000,
+                },
+            ]
+        ),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+    diversifier.update_whitelisted_tokens(&vec![&env, token_address.clone(), usdc_address.clone()]);
+
+    // Targets have to be whitelisted swap tokens of the input token
+    assert_eq!(
+        diversifier.try_update_basket(
+            &token_address,
+            &vec![
+                &env,
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 10000,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidSwapToken))
+    );
+    diversifier.update_whitelisted_swap_tokens(&token_address, &vec![&env, usdc_address.clone()]);
+
+    // Weights don't sum up to 10000
+    assert_eq!(
+        diversifier.try_update_basket(
+            &token_address,
+            &vec![
+                &env,
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 5000,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidBasket))
+    );
+    // Weights must be positive
+    assert_eq!(
+        diversifier.try_update_basket(
+            &token_address,
+            &vec![
+                &env,
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 10100,
+                },
+                DiversifierBasketTarget {
+                    token: token_address.clone(),
+                    weight: -100,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidBasket))
+    );
+    // Targets cannot be duplicated
+    assert_eq!(
+        diversifier.try_update_basket(
+            &token_address,
+            &vec![
+                &env,
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 5000,
+                },
+                DiversifierBasketTarget {
+                    token: usdc_address.clone(),
+                    weight: 5000,
+                },
+            ]
+        ),
+        Err(Ok(Error::InvalidBasket))
+    );
+
+    // An empty basket removes the configuration
+    diversifier.update_basket(
+        &token_address,
+        &vec![
+            &env,
+            DiversifierBasketTarget {
+                token: usdc_address,
+                weight: 10000,
+            },
+        ],
+    );
+    diversifier.update_basket(&token_address, &vec![&env]);
+    assert_eq!(diversifier.get_basket(&token_address), vec![&env]);
+}
+
+#[test]
+fn test_basket_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert_eq!(
+        diversifier.try_diversify_basket(&token_address, &1000, &vec![&env], &0),
+        Err(Ok(Error::BasketNotFound))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_update_basket(&token_address, &vec![&env])
+        .is_err());
+}
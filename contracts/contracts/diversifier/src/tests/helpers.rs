@@ -0,0 +1,191 @@
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, token, vec, Address, Bytes, BytesN, Env,
+    IntoVal, Val, Vec,
+};
+use sorosplits_utils::{
+    dex::DexAdapterInterface,
+    oracle::{Asset, PriceData, PriceOracleInterface},
+    testutils::{add_liquidity, create_soroswap_router, soroswap_router},
+    types::ShareDataKey,
+};
+use token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
+
+use crate::contract::{splitter_contract, Diversifier, DiversifierClient};
+
+pub mod soroswap_adapter {
+    soroban_sdk::contractimport!(
+        file = "../../target/wasm32-unknown-unknown/release/sorosplits_soroswap_adapter.wasm",
+    );
+}
+
+/// DEX adapter that swaps every token 1:1 from its own balance
+#[contract]
+pub struct MockDexAdapter;
+
+#[contractimpl]
+impl DexAdapterInterface for MockDexAdapter {
+    fn quote(_env: Env, swap_path: Vec<Address>, amount_in: i128) -> Option<i128> {
+        if swap_path.len() < 2 {
+            return None;
+        }
+        Some(amount_in)
+    }
+
+    fn swap(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount_in: i128,
+        _min_amount_out: i128,
+        to: Address,
+    ) -> i128 {
+        TokenClient::new(&env, &swap_path.last().unwrap()).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount_in,
+        );
+        amount_in
+    }
+}
+
+/// SEP-40 price oracle with prices set by the tests
+#[contract]
+pub struct MockPriceOracle;
+
+#[contractimpl]
+impl MockPriceOracle {
+    pub fn set_price(env: Env, token_address: Address, price: i128) {
+        let price_data = PriceData {
+            price,
+            timestamp: env.ledger().timestamp(),
+        };
+        env.storage().instance().set(&token_address, &price_data);
+    }
+}
+
+#[contractimpl]
+impl PriceOracleInterface for MockPriceOracle {
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData> {
+        match asset {
+            Asset::Stellar(token_address) => env.storage().instance().get(&token_address),
+            Asset::Other(_) => None,
+        }
+    }
+}
+
+/// Registers the Soroswap adapter for the router and returns its address
+pub fn create_soroswap_adapter(e: &Env, router: &soroswap_router::Client) -> Address {
+    let deployer = Address::generate(e);
+    let salt = BytesN::from_array(e, &[0; 32]);
+    let adapter_address = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    e.register_contract_wasm(&adapter_address, soroswap_adapter::WASM);
+    soroswap_adapter::Client::new(e, &adapter_address).init_adapter(
+        &deployer,
+        &salt,
+        &router.address,
+    );
+    adapter_address
+}
+
+pub fn create_diversifier(e: &Env) -> (DiversifierClient, Address) {
+    let contract_id = &e.register_contract(None, Diversifier);
+    (DiversifierClient::new(e, contract_id), contract_id.clone())
+}
+
+pub fn create_diversifier_with_shares<'a>(
+    e: &'a Env,
+    admin: &Address,
+    shares: &Vec<ShareDataKey>,
+    dex_adapter_address: &Address,
+) -> (DiversifierClient<'a>, Address) {
+    let (client, contract_id) = create_diversifier(e);
+    let splitter_wasm_hash = e.deployer().upload_contract_wasm(splitter_contract::WASM);
+    let splitter_init_args: Vec<Val> = (
+        Bytes::from_slice(e, "Diversifier Contract".as_bytes()),
+        shares.clone(),
+        true,
+    )
+        .into_val(e);
+    client.init_diversifier(
+        admin,
+        &splitter_wasm_hash,
+        &BytesN::from_array(e, &[0; 32]),
+        &true,
+        &splitter_init_args,
+        dex_adapter_address,
+        &None,
+    );
+    (client, contract_id)
+}
+
+/// Creates a diversifier that swaps between two tokens over a Soroswap pool
+///
+/// Returns the swap path from the minted token to the other token.
+pub fn create_diversifier_with_swap_pair(
+    e: &Env,
+    amount: i128,
+) -> (DiversifierClient, Vec<ShareDataKey>, Vec<Address>) {
+    let admin = Address::generate(e);
+    let (_, token_admin_client, token_address) = create_token(e, &admin);
+    let (_, _, swap_token_address) = create_token(e, &admin);
+
+    let router = create_soroswap_router(e);
+    add_liquidity(
+        e,
+        &router,
+        &token_address,
+        &swap_token_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(e);
+    let (diversifier, diversifier_address) =
+        create_diversifier_with_shares(e, &admin, &shares, &create_soroswap_adapter(e, &router));
+
+    diversifier.update_whitelisted_tokens(&vec![
+        e,
+        token_address.clone(),
+        swap_token_address.clone(),
+    ]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![e, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&swap_token_address, &vec![e, token_address.clone()]);
+
+    token_admin_client.mint(&diversifier_address, &amount);
+
+    (
+        diversifier,
+        shares,
+        vec![e, token_address, swap_token_address],
+    )
+}
+
+pub fn create_token<'a>(
+    e: &Env,
+    admin: &Address,
+) -> (TokenClient<'a>, TokenAdminClient<'a>, Address) {
+    let contract_id = e.register_stellar_asset_contract(admin.clone());
+    (
+        TokenClient::new(e, &contract_id),
+        TokenAdminClient::new(e, &contract_id),
+        contract_id,
+    )
+}
+
+pub fn get_default_share_data(env: &Env) -> Vec<ShareDataKey> {
+    vec![
+        env,
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 8050,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 1950,
+        },
+    ]
+}
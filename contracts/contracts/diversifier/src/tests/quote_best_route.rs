@@ -0,0 +1,80 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_a) = create_token(&env, &admin);
+    let (_, _, token_b) = create_token(&env, &admin);
+    let (_, _, token_c) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    // The direct pair has less liquidity than the pairs through token B
+    add_liquidity(&env, &router, &token_a, &token_c, 10_000, 10_000);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+    add_liquidity(&env, &router, &token_b, &token_c, 1_000_000, 1_000_000);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    let direct_path = vec![&env, token_a.clone(), token_c.clone()];
+    assert_eq!(
+        diversifier.quote_best_route(&token_a, &token_c, &1000),
+        (
+            direct_path.clone(),
+            diversifier.quote_swap(&direct_path, &1000)
+        )
+    );
+
+    diversifier.update_intermediate_tokens(&vec![&env, token_b.clone()]);
+
+    let hop_path = vec![&env, token_a.clone(), token_b.clone(), token_c.clone()];
+    assert_eq!(
+        diversifier.quote_best_route(&token_a, &token_c, &1000),
+        (hop_path.clone(), diversifier.quote_swap(&hop_path, &1000))
+    );
+}
+
+#[test]
+fn test_route_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_a) = create_token(&env, &admin);
+    let (_, _, token_b) = create_token(&env, &admin);
+    let (_, _, token_c) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &create_soroswap_adapter(&env, &router),
+    );
+    diversifier.update_intermediate_tokens(&vec![&env, token_b]);
+
+    assert_eq!(
+        diversifier.try_quote_best_route(&token_a, &token_c, &1000),
+        Err(Ok(Error::RouteNotFound))
+    );
+}
@@ -0,0 +1,96 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_diversifier, create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_a) = create_token(&env, &admin);
+    let (_, _, token_b) = create_token(&env, &admin);
+    let (_, _, token_c) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+    add_liquidity(&env, &router, &token_b, &token_c, 1_000_000, 2_000_000);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    // Single hop
+    let swap_path = vec![&env, token_a.clone(), token_b.clone()];
+    assert_eq!(diversifier.quote_swap(&swap_path, &1000), 996);
+
+    // Every hop is quoted with the reserves of its own pair
+    let swap_path = vec![&env, token_a.clone(), token_b.clone(), token_c.clone()];
+    let amounts_out = router.router_get_amounts_out(&1000, &swap_path);
+    assert_eq!(amounts_out.get(1).unwrap(), 996);
+    assert_eq!(
+        diversifier.quote_swap(&swap_path, &1000),
+        amounts_out.last().unwrap()
+    );
+}
+
+#[test]
+fn test_invalid_swap_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_a) = create_token(&env, &admin);
+    let (_, _, token_b) = create_token(&env, &admin);
+    let (_, _, token_c) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    assert_eq!(
+        diversifier.try_quote_swap(&vec![&env, token_a.clone()], &1000),
+        Err(Ok(Error::InvalidSwapPath))
+    );
+    assert_eq!(
+        diversifier.try_quote_swap(&vec![&env, token_a.clone(), token_a.clone()], &1000),
+        Err(Ok(Error::InvalidSwapPath))
+    );
+    // There is no pair for the second hop
+    assert_eq!(
+        diversifier.try_quote_swap(&vec![&env, token_a, token_b, token_c], &1000),
+        Err(Ok(Error::SwapPairNotFound))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (diversifier, _) = create_diversifier(&env);
+
+    assert_eq!(
+        diversifier.try_quote_swap(
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &1000
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_diversifier, create_diversifier_with_swap_pair},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, shares, swap_path) = create_diversifier_with_swap_pair(&env, 2000);
+    let swap_token_address = swap_path.last().unwrap();
+
+    // The swap is split into two chunks
+    diversifier.swap_to_pending(&swap_path, &1000, &990, &0);
+    assert_eq!(diversifier.get_pending_amount(&swap_token_address), 996);
+    diversifier.swap_to_pending(&swap_path, &1000, &990, &0);
+    assert_eq!(diversifier.get_pending_amount(&swap_token_address), 1990);
+
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        0
+    );
+
+    diversifier.settle(&swap_token_address);
+
+    assert_eq!(diversifier.get_pending_amount(&swap_token_address), 0);
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        1601
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        388
+    );
+}
+
+#[test]
+fn test_pending_amount_not_swapped() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 2000);
+    let token_address = swap_path.get(0).unwrap();
+    let swap_token_address = swap_path.last().unwrap();
+
+    diversifier.swap_to_pending(&swap_path, &1000, &990, &0);
+
+    // The whole balance of the swap token is pending settlement
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(
+            &vec![&env, swap_token_address, token_address],
+            &1,
+            &0,
+            &0
+        ),
+        Err(Ok(Error::InsufficientTokenBalance))
+    );
+}
+
+#[test]
+fn test_pending_amount_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 2000);
+
+    assert_eq!(
+        diversifier.try_settle(&swap_path.last().unwrap()),
+        Err(Ok(Error::PendingAmountNotFound))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (diversifier, _) = create_diversifier(&env);
+
+    assert_eq!(
+        diversifier.try_settle(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
@@ -0,0 +1,368 @@
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    token::{Client as TokenClient, StellarAssetClient as TokenAdminClient},
+    vec, Address, Env, Vec,
+};
+use sorosplits_utils::{
+    dex::DexAdapterInterface,
+    testutils::{add_liquidity, create_soroswap_router},
+};
+
+use crate::{
+    errors::Error,
+    storage::oracle::DiversifierPriceOracle,
+    tests::helpers::{
+        create_diversifier_with_shares, create_diversifier_with_swap_pair, create_soroswap_adapter,
+        create_token, get_default_share_data, MockPriceOracle, MockPriceOracleClient,
+    },
+};
+
+/// DEX adapter that reports a 1:1 swap but only sends back half of it
+#[contract]
+pub struct MockShortDexAdapter;
+
+#[contractimpl]
+impl DexAdapterInterface for MockShortDexAdapter {
+    fn quote(_env: Env, swap_path: Vec<Address>, amount_in: i128) -> Option<i128> {
+        if swap_path.len() < 2 {
+            return None;
+        }
+        Some(amount_in)
+    }
+
+    fn swap(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount_in: i128,
+        _min_amount_out: i128,
+        to: Address,
+    ) -> i128 {
+        TokenClient::new(&env, &swap_path.last().unwrap()).transfer(
+            &env.current_contract_address(),
+            &to,
+            &(amount_in / 2),
+        );
+        amount_in
+    }
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, shares, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+    let swap_token_address = swap_path.last().unwrap();
+
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &990, &0);
+
+    // 1000 tokens in for 996 tokens out after the 0.3% pool fee
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        801
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        194
+    );
+}
+
+#[test]
+fn test_multi_hop() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, _, hop_token_address) = create_token(&env, &admin);
+    let (_, _, swap_token_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &hop_token_address,
+        1_000_000,
+        1_000_000,
+    );
+    add_liquidity(
+        &env,
+        &router,
+        &hop_token_address,
+        &swap_token_address,
+        1_000_000,
+        2_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+
+    token_admin_client.mint(&diversifier_address, &1000);
+
+    let swap_path = vec![
+        &env,
+        token_address,
+        hop_token_address,
+        swap_token_address.clone(),
+    ];
+    let amount_out = diversifier.quote_swap(&swap_path, &1000);
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &(amount_out + 1), &0),
+        Err(Ok(Error::SlippageExceeded))
+    );
+
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &amount_out, &0);
+
+    assert_eq!(
+        diversifier.get_token_balance(&swap_token_address).balance,
+        amount_out
+    );
+}
+
+#[test]
+fn test_best_route() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, _, hop_token_address) = create_token(&env, &admin);
+    let (_, _, swap_token_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &hop_token_address,
+        1_000_000,
+        1_000_000,
+    );
+    add_liquidity(
+        &env,
+        &router,
+        &hop_token_address,
+        &swap_token_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+
+    assert_eq!(
+        diversifier.try_swap_and_distribute_best(
+            &token_address,
+            &swap_token_address,
+            &1000,
+            &0,
+            &0
+        ),
+        Err(Ok(Error::InsufficientTokenBalance))
+    );
+
+    token_admin_client.mint(&diversifier_address, &1000);
+
+    assert_eq!(
+        diversifier.try_swap_and_distribute_best(&token_address, &swap_token_address, &0, &0, &0),
+        Err(Ok(Error::InsufficientTokenBalance))
+    );
+
+    // There is no direct pair between the tokens
+    assert_eq!(
+        diversifier.try_swap_and_distribute_best(
+            &token_address,
+            &swap_token_address,
+            &1000,
+            &0,
+            &0
+        ),
+        Err(Ok(Error::RouteNotFound))
+    );
+
+    diversifier.update_intermediate_tokens(&vec![&env, hop_token_address]);
+    let (_, amount_out) = diversifier.quote_best_route(&token_address, &swap_token_address, &1000);
+
+    diversifier.swap_and_distribute_best(
+        &token_address,
+        &swap_token_address,
+        &1000,
+        &amount_out,
+        &0,
+    );
+
+    assert_eq!(
+        diversifier.get_token_balance(&swap_token_address).balance,
+        amount_out
+    );
+}
+
+#[test]
+fn test_slippage_exceeded() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &997, &0),
+        Err(Ok(Error::SlippageExceeded))
+    );
+}
+
+#[test]
+fn test_untrusted_swap_output() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, shares, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+    let swap_token_address = swap_path.last().unwrap();
+
+    let adapter_address = env.register_contract(None, MockShortDexAdapter);
+    TokenAdminClient::new(&env, &swap_token_address).mint(&adapter_address, &1000);
+    diversifier.update_dex_adapter_address(&adapter_address);
+
+    // The adapter reports 1000 tokens out but only sends 500
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &1000, &0),
+        Err(Ok(Error::SlippageExceeded))
+    );
+
+    // Only the received tokens are distributed
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &500, &0);
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        402
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        97
+    );
+}
+
+#[test]
+fn test_deadline_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &990, &99),
+        Err(Ok(Error::SwapDeadlineExpired))
+    );
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &990, &100);
+}
+
+#[test]
+fn test_oracle_price() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+    let token_address = swap_path.get(0).unwrap();
+    let swap_token_address = swap_path.last().unwrap();
+
+    let oracle = MockPriceOracleClient::new(&env, &env.register_contract(None, MockPriceOracle));
+    oracle.set_price(&token_address, &100_000_000_000_000);
+    diversifier.update_price_oracle(&Some(DiversifierPriceOracle {
+        oracle_address: oracle.address.clone(),
+        max_deviation: 30,
+        max_price_age: 300,
+    }));
+
+    // There is no price for the swap token
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &990, &0),
+        Err(Ok(Error::OraclePriceNotFound))
+    );
+
+    // 996 tokens out is 0.4% below the oracle price
+    oracle.set_price(&swap_token_address, &100_000_000_000_000);
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &990, &0),
+        Err(Ok(Error::PriceDeviationExceeded))
+    );
+
+    diversifier.update_price_oracle(&Some(DiversifierPriceOracle {
+        oracle_address: oracle.address.clone(),
+        max_deviation: 50,
+        max_price_age: 300,
+    }));
+
+    // The oracle prices are too old
+    env.ledger().with_mut(|li| li.timestamp += 301);
+    assert_eq!(
+        diversifier.try_swap_and_distribute_tokens(&swap_path, &1000, &990, &0),
+        Err(Ok(Error::OraclePriceNotFound))
+    );
+
+    oracle.set_price(&token_address, &100_000_000_000_000);
+    oracle.set_price(&swap_token_address, &100_000_000_000_000);
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &990, &0);
+
+    assert_eq!(
+        diversifier.get_token_balance(&swap_token_address).balance,
+        996
+    );
+}
+
+#[test]
+fn test_oracle_price_max_age() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let (diversifier, _, swap_path) = create_diversifier_with_swap_pair(&env, 1000);
+    let token_address = swap_path.get(0).unwrap();
+    let swap_token_address = swap_path.last().unwrap();
+
+    env.ledger().with_mut(|li| li.timestamp = 1000);
+
+    let oracle = MockPriceOracleClient::new(&env, &env.register_contract(None, MockPriceOracle));
+    oracle.set_price(&token_address, &100_000_000_000_000);
+    oracle.set_price(&swap_token_address, &100_000_000_000_000);
+    diversifier.update_price_oracle(&Some(DiversifierPriceOracle {
+        oracle_address: oracle.address.clone(),
+        max_deviation: 50,
+        max_price_age: u64::MAX,
+    }));
+
+    // The price age does not overflow with the maximum value
+    env.ledger().with_mut(|li| li.timestamp += 301);
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &990, &0);
+
+    assert_eq!(
+        diversifier.get_token_balance(&swap_token_address).balance,
+        996
+    );
+}
@@ -0,0 +1,174 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (swap_token_client, _, swap_token_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &swap_token_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+
+    token_admin_client.mint(&diversifier_address, &1000);
+
+    let swap_path = vec![&env, token_address, swap_token_address.clone()];
+    diversifier.swap_to_splitter(&swap_path, &1000, &990, &0);
+
+    // The output is kept in the splitter without being distributed
+    let splitter_address = diversifier.get_diversifier_config().splitter_address;
+    assert_eq!(swap_token_client.balance(&splitter_address), 996);
+    assert_eq!(swap_token_client.balance(&diversifier_address), 0);
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        0
+    );
+
+    diversifier.distribute_tokens(&swap_token_address, &996);
+
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        801
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        194
+    );
+}
+
+#[test]
+fn test_poke_while_active() {
+    let env = Env::default();
+    env.mock_all_auths_allowing_non_root_auth();
+    env.budget().reset_unlimited();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let admin = Address::generate(&env);
+    let (_, token_admin_client, token_address) = create_token(&env, &admin);
+    let (swap_token_client, swap_token_admin_client, swap_token_address) =
+        create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &swap_token_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+    diversifier.update_distribution_schedule(&swap_token_address, &1000, &0);
+
+    token_admin_client.mint(&diversifier_address, &1000);
+    swap_token_admin_client.mint(&diversifier_address, &500);
+
+    let swap_path = vec![&env, token_address, swap_token_address.clone()];
+    diversifier.swap_to_splitter(&swap_path, &1000, &990, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1100);
+    diversifier.poke(&swap_token_address, &Address::generate(&env));
+
+    // Only the swap output in the splitter is distributed, the diversifier balance is kept
+    assert_eq!(swap_token_client.balance(&diversifier_address), 500);
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        801
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        194
+    );
+}
+
+#[test]
+fn test_not_active() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+    diversifier.toggle_diversifier();
+
+    assert_eq!(
+        diversifier.try_swap_to_splitter(
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &1000,
+            &0,
+            &0
+        ),
+        Err(Ok(Error::NotActive))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_swap_to_splitter(
+            &vec![&env, Address::generate(&env), Address::generate(&env)],
+            &1000,
+            &0,
+            &0
+        )
+        .is_err());
+}
@@ -0,0 +1,121 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_diversifier, create_diversifier_with_shares, create_soroswap_adapter, create_token,
+        get_default_share_data, MockDexAdapter,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let dex_adapter_address = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &dex_adapter_address,
+    );
+
+    assert_eq!(
+        diversifier.get_diversifier_config().dex_adapter_address,
+        dex_adapter_address
+    );
+
+    let new_dex_adapter_address = Address::generate(&env);
+    diversifier.update_dex_adapter_address(&new_dex_adapter_address);
+
+    assert_eq!(
+        diversifier.get_diversifier_config().dex_adapter_address,
+        new_dex_adapter_address
+    );
+}
+
+#[test]
+fn test_swap_with_new_adapter() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (token_client, token_admin_client, token_address) = create_token(&env, &admin);
+    let (_, swap_token_admin_client, swap_token_address) = create_token(&env, &admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(
+        &env,
+        &router,
+        &token_address,
+        &swap_token_address,
+        1_000_000,
+        1_000_000,
+    );
+
+    let shares = get_default_share_data(&env);
+    let (diversifier, diversifier_address) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &shares,
+        &create_soroswap_adapter(&env, &router),
+    );
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+
+    let swap_path = vec![&env, token_address.clone(), swap_token_address.clone()];
+    assert_eq!(diversifier.quote_swap(&swap_path, &1000), 996);
+
+    // Swaps go through the new adapter without any other change
+    let mock_adapter_address = env.register_contract(None, MockDexAdapter);
+    swap_token_admin_client.mint(&mock_adapter_address, &1000);
+    diversifier.update_dex_adapter_address(&mock_adapter_address);
+
+    assert_eq!(diversifier.quote_swap(&swap_path, &1000), 1000);
+
+    token_admin_client.mint(&diversifier_address, &1000);
+    diversifier.swap_and_distribute_tokens(&swap_path, &1000, &1000, &0);
+
+    assert_eq!(token_client.balance(&mock_adapter_address), 1000);
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(0).unwrap().shareholder, &swap_token_address),
+        805
+    );
+    assert_eq!(
+        diversifier.get_allocation(&shares.get(1).unwrap().shareholder, &swap_token_address),
+        195
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (diversifier, _) = create_diversifier(&env);
+
+    assert_eq!(
+        diversifier.try_update_dex_adapter_address(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_update_dex_adapter_address(&Address::generate(&env))
+        .is_err());
+}
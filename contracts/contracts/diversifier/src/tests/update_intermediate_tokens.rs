@@ -0,0 +1,117 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::swaps::MAX_INTERMEDIATE_TOKENS,
+    tests::helpers::{create_diversifier_with_shares, create_token, get_default_share_data},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+    assert_eq!(diversifier.list_intermediate_tokens(), vec![&env]);
+
+    let (_, _, token_address) = create_token(&env, &admin);
+    diversifier.update_intermediate_tokens(&vec![&env, token_address.clone()]);
+
+    assert_eq!(
+        diversifier.list_intermediate_tokens(),
+        vec![&env, token_address]
+    );
+}
+
+#[test]
+fn test_invalid_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_update_intermediate_tokens(&vec![&env, Address::generate(&env)])
+        .is_err());
+}
+
+#[test]
+fn test_duplicate_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    let (_, _, token_address) = create_token(&env, &admin);
+    assert_eq!(
+        diversifier.try_update_intermediate_tokens(&vec![
+            &env,
+            token_address.clone(),
+            token_address.clone()
+        ]),
+        Err(Ok(Error::DuplicateIntermediateToken))
+    );
+}
+
+#[test]
+fn test_limit_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    let mut tokens = vec![&env];
+    for _ in 0..MAX_INTERMEDIATE_TOKENS {
+        let (_, _, token_address) = create_token(&env, &admin);
+        tokens.push_back(token_address);
+    }
+    diversifier.update_intermediate_tokens(&tokens);
+
+    let (_, _, token_address) = create_token(&env, &admin);
+    tokens.push_back(token_address);
+    assert_eq!(
+        diversifier.try_update_intermediate_tokens(&tokens),
+        Err(Ok(Error::IntermediateTokenLimitReached))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_update_intermediate_tokens(&vec![&env])
+        .is_err());
+}
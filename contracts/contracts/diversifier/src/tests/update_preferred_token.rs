@@ -0,0 +1,93 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_diversifier_with_shares, create_token, get_default_share_data},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let shares = get_default_share_data(&env);
+    let shareholder = shares.get(0).unwrap().shareholder;
+    let (diversifier, _) =
+        create_diversifier_with_shares(&env, &admin, &shares, &Address::generate(&env));
+
+    assert_eq!(diversifier.get_preferred_token(&shareholder), None);
+
+    diversifier.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    diversifier.update_preferred_token(&shareholder, &Some(token_address.clone()));
+    assert_eq!(
+        diversifier.get_preferred_token(&shareholder),
+        Some(token_address)
+    );
+
+    diversifier.update_preferred_token(&shareholder, &None);
+    assert_eq!(diversifier.get_preferred_token(&shareholder), None);
+}
+
+#[test]
+fn test_not_shareholder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert_eq!(
+        diversifier.try_update_preferred_token(&Address::generate(&env), &Some(token_address)),
+        Err(Ok(Error::NotShareholder))
+    );
+}
+
+#[test]
+fn test_token_not_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let shares = get_default_share_data(&env);
+    let (diversifier, _) =
+        create_diversifier_with_shares(&env, &admin, &shares, &Address::generate(&env));
+
+    // The token has to be whitelisted in the splitter to be distributed
+    assert_eq!(
+        diversifier
+            .try_update_preferred_token(&shares.get(0).unwrap().shareholder, &Some(token_address)),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+    assert_eq!(
+        diversifier.try_update_preferred_token(
+            &shares.get(0).unwrap().shareholder,
+            &Some(Address::generate(&env))
+        ),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let shares = get_default_share_data(&env);
+    let (diversifier, _) =
+        create_diversifier_with_shares(&env, &admin, &shares, &Address::generate(&env));
+
+    assert!(diversifier
+        .try_update_preferred_token(&shares.get(0).unwrap().shareholder, &None)
+        .is_err());
+}
@@ -0,0 +1,79 @@
+use soroban_sdk::{testutils::Address as _, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::oracle::DiversifierPriceOracle,
+    tests::helpers::{create_diversifier_with_shares, get_default_share_data},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+    assert_eq!(diversifier.get_price_oracle(), None);
+
+    let price_oracle = DiversifierPriceOracle {
+        oracle_address: Address::generate(&env),
+        max_deviation: 100,
+        max_price_age: 300,
+    };
+    diversifier.update_price_oracle(&Some(price_oracle.clone()));
+    assert_eq!(diversifier.get_price_oracle(), Some(price_oracle));
+
+    diversifier.update_price_oracle(&None);
+    assert_eq!(diversifier.get_price_oracle(), None);
+}
+
+#[test]
+fn test_invalid_price_oracle() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert_eq!(
+        diversifier.try_update_price_oracle(&Some(DiversifierPriceOracle {
+            oracle_address: Address::generate(&env),
+            max_deviation: 10001,
+            max_price_age: 300,
+        })),
+        Err(Ok(Error::InvalidPriceOracle))
+    );
+    assert_eq!(
+        diversifier.try_update_price_oracle(&Some(DiversifierPriceOracle {
+            oracle_address: Address::generate(&env),
+            max_deviation: 100,
+            max_price_age: 0,
+        })),
+        Err(Ok(Error::InvalidPriceOracle))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier.try_update_price_oracle(&None).is_err());
+}
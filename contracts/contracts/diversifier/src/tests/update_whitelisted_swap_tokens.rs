@@ -0,0 +1,75 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_diversifier_with_shares, create_token, get_default_share_data},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let (_, _, swap_token_address) = create_token(&env, &admin);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    diversifier.update_whitelisted_tokens(&vec![&env, swap_token_address.clone()]);
+    diversifier
+        .update_whitelisted_swap_tokens(&token_address, &vec![&env, swap_token_address.clone()]);
+
+    assert_eq!(
+        diversifier.list_whitelisted_swap_tokens(&token_address),
+        vec![&env, swap_token_address]
+    );
+}
+
+#[test]
+fn test_token_not_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let (_, _, token_address) = create_token(&env, &admin);
+    let (_, _, swap_token_address) = create_token(&env, &admin);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    // Swapped tokens are distributed, so they have to be whitelisted in the splitter
+    assert_eq!(
+        diversifier.try_update_whitelisted_swap_tokens(
+            &token_address,
+            &vec![&env, swap_token_address.clone()]
+        ),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (diversifier, _) = create_diversifier_with_shares(
+        &env,
+        &admin,
+        &get_default_share_data(&env),
+        &Address::generate(&env),
+    );
+
+    assert!(diversifier
+        .try_update_whitelisted_swap_tokens(&Address::generate(&env), &vec![&env])
+        .is_err());
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    NotInitialized = 401,
+    AlreadyInitialized = 402,
+    SwapPairNotFound = 403,
+    Unauthorized = 404,
+}
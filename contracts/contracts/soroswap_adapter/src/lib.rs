@@ -0,0 +1,164 @@
+#![no_std]
+
+use soroban_sdk::{
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contractimpl, contractmeta, panic_with_error, vec, Address, BytesN, Env, IntoVal,
+    Symbol, Vec,
+};
+use sorosplits_utils::{deploy::is_deployed_by, dex::DexAdapterInterface, token::get_token_client};
+
+use crate::{errors::Error, storage::SoroswapAdapterConfig};
+
+mod errors;
+mod storage;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) mod soroswap_router {
+    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_router.optimized.wasm",);
+}
+use soroswap_router::Client as SoroswapRouterClient;
+pub(crate) mod soroswap_pair {
+    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_pair.optimized.wasm",);
+}
+use soroswap_pair::Client as SoroswapPairClient;
+
+contractmeta!(
+    key = "desc",
+    val = "The Soroswap adapter contract is used by the Diversifier to swap tokens on Soroswap."
+);
+
+#[contract]
+pub struct SoroswapAdapter;
+
+#[contractimpl]
+impl SoroswapAdapter {
+    /// Initializes the adapter with the Soroswap router used for the swaps.
+    ///
+    /// This method can only be called once, by the account that deployed the adapter
+    /// with the given salt, so the router can't be set by another account first.
+    pub fn init_adapter(
+        env: Env,
+        deployer: Address,
+        salt: BytesN<32>,
+        router_address: Address,
+    ) -> Result<(), Error> {
+        deployer.require_auth();
+
+        if SoroswapAdapterConfig::exists(&env) {
+            return Err(Error::AlreadyInitialized);
+        }
+        if !is_deployed_by(&env, &deployer, &salt) {
+            return Err(Error::Unauthorized);
+        }
+
+        SoroswapAdapterConfig::init(&env, router_address);
+        Ok(())
+    }
+
+    /// Returns the address of the Soroswap router used for the swaps.
+    pub fn get_router_address(env: Env) -> Result<Address, Error> {
+        Ok(SoroswapAdapterConfig::get(&env)?.router_address)
+    }
+}
+
+#[contractimpl]
+impl DexAdapterInterface for SoroswapAdapter {
+    fn quote(env: Env, swap_path: Vec<Address>, amount_in: i128) -> Option<i128> {
+        let router_client = get_router_client(&env);
+        get_first_pair(&env, &router_client, &swap_path)?;
+
+        let amounts_out = router_client.router_get_amounts_out(&amount_in, &swap_path);
+        amounts_out.last()
+    }
+
+    fn swap(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount_in: i128,
+        min_amount_out: i128,
+        to: Address,
+    ) -> i128 {
+        let router_client = get_router_client(&env);
+        let pair_address = match get_first_pair(&env, &router_client, &swap_path) {
+            Some(pair_address) => pair_address,
+            None => panic_with_error!(&env, Error::SwapPairNotFound),
+        };
+
+        // Authorize token transfer for the current contract
+        // Without this tokens cannot be transferred from the current contract to the first pair contract
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: swap_path.get(0).unwrap(),
+                    fn_name: Symbol::new(&env, "transfer"),
+                    args: (env.current_contract_address(), pair_address, amount_in).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+
+        // The router only requires the deadline to be later than the current ledger timestamp,
+        // the swap deadline is checked by the caller
+        let amounts_out = router_client.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &min_amount_out,
+            &swap_path,
+            &env.current_contract_address(),
+            &(env.ledger().timestamp() + 1),
+        );
+        let amount_out = amounts_out.last().unwrap();
+
+        get_token_client(&env, &swap_path.last().unwrap()).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount_out,
+        );
+
+        amount_out
+    }
+}
+
+fn get_router_client(env: &Env) -> SoroswapRouterClient<'_> {
+    match SoroswapAdapterConfig::get(env) {
+        Ok(config) => SoroswapRouterClient::new(env, &config.router_address),
+        Err(err) => panic_with_error!(env, err),
+    }
+}
+
+/// Returns the address of the first pair in the swap path
+///
+/// Returns `None` if the swap path is invalid or any hop doesn't have a pair with liquidity.
+fn get_first_pair(
+    env: &Env,
+    router_client: &SoroswapRouterClient,
+    swap_path: &Vec<Address>,
+) -> Option<Address> {
+    if swap_path.len() < 2 {
+        return None;
+    }
+
+    let mut first_pair_address: Option<Address> = None;
+    for i in 1..swap_path.len() {
+        let pair_address = match router_client
+            .try_router_pair_for(&swap_path.get(i - 1).unwrap(), &swap_path.get(i).unwrap())
+        {
+            Ok(Ok(pair_address)) => pair_address,
+            _ => return None,
+        };
+
+        // Calling a pair that is not deployed fails
+        match SoroswapPairClient::new(env, &pair_address).try_get_reserves() {
+            Ok(Ok((reserve_0, reserve_1))) if reserve_0 > 0 && reserve_1 > 0 => {}
+            _ => return None,
+        }
+
+        if first_pair_address.is_none() {
+            first_pair_address = Some(pair_address);
+        }
+    }
+
+    first_pair_address
+}
+
+mod tests;
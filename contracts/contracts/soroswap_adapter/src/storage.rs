@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address, Env};
+use sorosplits_utils::storage::bump_instance;
+
+use crate::errors::Error;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum SoroswapAdapterDataKeys {
+    Config,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct SoroswapAdapterConfig {
+    /// Address of the Soroswap router used for the swaps
+    pub router_address: Address,
+}
+impl SoroswapAdapterConfig {
+    /// Initializes the config with the given router address
+    pub fn init(e: &Env, router_address: Address) {
+        bump_instance(e);
+        let key = SoroswapAdapterDataKeys::Config;
+        let config = SoroswapAdapterConfig { router_address };
+        e.storage().instance().set(&key, &config);
+    }
+
+    /// Returns the config
+    pub fn get(e: &Env) -> Result<SoroswapAdapterConfig, Error> {
+        if !SoroswapAdapterConfig::exists(e) {
+            return Err(Error::NotInitialized);
+        }
+        bump_instance(e);
+        let key = SoroswapAdapterDataKeys::Config;
+        Ok(e.storage().instance().get(&key).unwrap())
+    }
+
+    /// Returns true if SoroswapAdapterConfig exists in the storage
+    pub fn exists(e: &Env) -> bool {
+        let key = SoroswapAdapterDataKeys::Config;
+        e.storage().instance().has(&key)
+    }
+}
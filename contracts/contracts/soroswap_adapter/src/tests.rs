@@ -0,0 +1,181 @@
+#![cfg(test)]
+extern crate std;
+
+use soroban_sdk::{
+    testutils::Address as _,
+    token::{Client as TokenClient, StellarAssetClient as TokenAdminClient},
+    vec, Address, BytesN, Env,
+};
+use sorosplits_utils::testutils::{add_liquidity, create_soroswap_router};
+
+use crate::{errors::Error, SoroswapAdapter, SoroswapAdapterClient};
+
+/// Registers the adapter at the address it gets when the deployer deploys it with the salt
+fn register_adapter<'a>(
+    e: &Env,
+    deployer: &Address,
+    salt: &BytesN<32>,
+) -> SoroswapAdapterClient<'a> {
+    let adapter_address = e
+        .deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address();
+    SoroswapAdapterClient::new(e, &e.register_contract(&adapter_address, SoroswapAdapter))
+}
+
+fn create_adapter<'a>(e: &Env, router_address: &Address) -> SoroswapAdapterClient<'a> {
+    let deployer = Address::generate(e);
+    let salt = BytesN::from_array(e, &[0; 32]);
+    let adapter = register_adapter(e, &deployer, &salt);
+    adapter.init_adapter(&deployer, &salt, router_address);
+    adapter
+}
+
+#[test]
+fn test_init_adapter() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[0; 32]);
+    let adapter = register_adapter(&env, &deployer, &salt);
+    assert_eq!(
+        adapter.try_get_router_address(),
+        Err(Ok(Error::NotInitialized))
+    );
+
+    // Only the account that deployed the adapter can initialize it
+    assert_eq!(
+        adapter.try_init_adapter(&Address::generate(&env), &salt, &Address::generate(&env)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    let router_address = Address::generate(&env);
+    adapter.init_adapter(&deployer, &salt, &router_address);
+    assert_eq!(adapter.get_router_address(), router_address);
+
+    assert_eq!(
+        adapter.try_init_adapter(&deployer, &salt, &Address::generate(&env)),
+        Err(Ok(Error::AlreadyInitialized))
+    );
+}
+
+#[test]
+fn test_init_adapter_unauthorized() {
+    let env = Env::default();
+
+    let deployer = Address::generate(&env);
+    let salt = BytesN::from_array(&env, &[0; 32]);
+    let adapter = register_adapter(&env, &deployer, &salt);
+
+    assert!(adapter
+        .try_init_adapter(&deployer, &salt, &Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_quote() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let token_a = env.register_stellar_asset_contract(admin.clone());
+    let token_b = env.register_stellar_asset_contract(admin.clone());
+    let token_c = env.register_stellar_asset_contract(admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+
+    let adapter = create_adapter(&env, &router.address);
+
+    let swap_path = vec![&env, token_a.clone(), token_b.clone()];
+    assert_eq!(
+        adapter.quote(&swap_path, &1000),
+        router.router_get_amounts_out(&1000, &swap_path).last()
+    );
+
+    assert_eq!(adapter.quote(&vec![&env, token_a.clone()], &1000), None);
+    assert_eq!(
+        adapter.quote(&vec![&env, token_a.clone(), token_a.clone()], &1000),
+        None
+    );
+    // There is no pair for the second hop
+    assert_eq!(
+        adapter.quote(&vec![&env, token_a, token_b, token_c], &1000),
+        None
+    );
+}
+
+#[test]
+fn test_swap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let token_a = env.register_stellar_asset_contract(admin.clone());
+    let token_b = env.register_stellar_asset_contract(admin.clone());
+    let token_c = env.register_stellar_asset_contract(admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+    add_liquidity(&env, &router, &token_b, &token_c, 1_000_000, 1_000_000);
+
+    let adapter = create_adapter(&env, &router.address);
+    let recipient = Address::generate(&env);
+
+    let swap_path = vec![&env, token_a.clone(), token_b.clone(), token_c.clone()];
+    let quote = adapter.quote(&swap_path, &1000).unwrap();
+
+    TokenAdminClient::new(&env, &token_a).mint(&adapter.address, &1000);
+    let amount_out = adapter.swap(&swap_path, &1000, &quote, &recipient);
+
+    assert_eq!(amount_out, quote);
+    assert_eq!(
+        TokenClient::new(&env, &token_c).balance(&recipient),
+        amount_out
+    );
+    assert_eq!(
+        TokenClient::new(&env, &token_a).balance(&adapter.address),
+        0
+    );
+    assert_eq!(
+        TokenClient::new(&env, &token_c).balance(&adapter.address),
+        0
+    );
+}
+
+#[test]
+fn test_swap_failures() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.budget().reset_unlimited();
+
+    let admin = Address::generate(&env);
+    let token_a = env.register_stellar_asset_contract(admin.clone());
+    let token_b = env.register_stellar_asset_contract(admin.clone());
+    let token_c = env.register_stellar_asset_contract(admin);
+
+    let router = create_soroswap_router(&env);
+    add_liquidity(&env, &router, &token_a, &token_b, 1_000_000, 1_000_000);
+
+    let adapter = create_adapter(&env, &router.address);
+    let recipient = Address::generate(&env);
+    TokenAdminClient::new(&env, &token_a).mint(&adapter.address, &1000);
+
+    // There is no pair for the tokens
+    assert_eq!(
+        adapter.try_swap(&vec![&env, token_a.clone(), token_c], &1000, &0, &recipient),
+        Err(Ok(soroban_sdk::Error::from_contract_error(
+            Error::SwapPairNotFound as u32
+        )))
+    );
+
+    // Minimum amount out is higher than the output
+    let swap_path = vec![&env, token_a, token_b];
+    let quote = adapter.quote(&swap_path, &1000).unwrap();
+    assert!(adapter
+        .try_swap(&swap_path, &1000, &(quote + 1), &recipient)
+        .is_err());
+}
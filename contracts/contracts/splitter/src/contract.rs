@@ -1,7 +1,20 @@
-use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, Env, Vec};
-use sorosplits_utils::types::ShareDataKey;
-
-use crate::{errors::Error, logic::execute, logic::query, storage::config::ConfigDataKey};
+use soroban_sdk::{contract, contractimpl, contractmeta, Address, Bytes, BytesN, Env, Vec};
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
+
+use crate::{
+    errors::Error,
+    logic::execute,
+    logic::query,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenBalanceDataKey, TokenInfoDataKey, WhitelistedTokenDataKey},
+        history::LifetimeTotalsDataKey,
+        merkle::MerkleDistributionDataKey,
+        rounds::DistributionRoundDataKey,
+        schedules::ScheduleDataKey,
+        vouchers::VoucherDataKey,
+    },
+};
 
 contractmeta!(
     key = "desc",
@@ -20,12 +33,14 @@ pub trait SplitterTrait {
     /// * `name` - The name of the contract
     /// * `shares` - The shareholders with their shares
     /// * `updatable` - Whether the contract is mutable or not
+    /// * `protocol_fee` - The optional protocol fee taken from each distribution
     fn init_splitter(
         env: Env,
         admin: Address,
         name: Bytes,
         shares: Vec<ShareDataKey>,
         updatable: bool,
+        protocol_fee: Option<ProtocolFeeDataKey>,
     ) -> Result<(), Error>;
 
     // ========== Execute Functions ==========
@@ -42,6 +57,55 @@ pub trait SplitterTrait {
     /// * `tokens` - The list of token addresses to whitelist
     fn update_whitelisted_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error>;
 
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Adds a token to the whitelisted tokens.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to whitelist
+    fn add_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Removes a token from the whitelisted tokens.
+    ///
+    /// The token cannot be removed while it has unwithdrawn allocations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to remove
+    fn remove_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the minimum distribution amount for a whitelisted token.
+    ///
+    /// Distributions below the minimum amount are rejected to avoid dust allocations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the whitelisted token
+    /// * `min_distribution` - The minimum amount of tokens that can be distributed at once
+    fn update_min_distribution(
+        env: Env,
+        token_address: Address,
+        min_distribution: i128,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Scales down the allocations of a token when the balance is lower than the total allocation.
+    ///
+    /// This can be used after the token issuer claws back tokens from the contract.
+    /// Every allocation is reduced by the ratio of the balance to the total allocation.
+    /// The reduction is recorded as written off in the lifetime totals.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    fn reconcile_allocations(env: Env, token_address: Address) -> Result<(), Error>;
+
     /// **ADMIN ONLY FUNCTION**
     ///
     /// Transfers unused tokens to the recipient.
@@ -73,11 +137,95 @@ pub trait SplitterTrait {
     /// * `amount` - The amount of tokens to distribute
     fn distribute_tokens(env: Env, token_address: Address, amount: i128) -> Result<(), Error>;
 
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Distributes tokens to the given shareholders only.
+    ///
+    /// The shares of the given shareholders are re-normalized to split the whole amount.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute
+    /// * `amount` - The amount of tokens to distribute
+    /// * `shareholders` - The shareholders to distribute the tokens to, without duplicates
+    fn distribute_to_shareholders(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        shareholders: Vec<Address>,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Creates a Merkle distribution for a token.
+    ///
+    /// The amount is reserved from the unused balance and can be claimed by the
+    /// recipients in the Merkle tree with `claim_merkle_allocation`. Each leaf of the
+    /// tree is the SHA-256 hash of the XDR encoded `(index, recipient, amount)` tuple.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute
+    /// * `amount` - The total amount of tokens in the Merkle tree
+    /// * `merkle_root` - The root of the Merkle tree
+    /// * `expiry` - The last ledger sequence the allocations can be claimed on
+    ///
+    /// ## Returns
+    ///
+    /// * `u32` - The ID of the Merkle distribution
+    fn create_merkle_distribution(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        merkle_root: BytesN<32>,
+        expiry: u32,
+    ) -> Result<u32, Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Closes an expired Merkle distribution.
+    ///
+    /// The unclaimed amount is released from the reservation back to the unused balance.
+    ///
+    /// ## Arguments
+    ///
+    /// * `distribution_id` - The ID of the Merkle distribution
+    ///
+    /// ## Returns
+    ///
+    /// * `i128` - The released amount
+    fn close_merkle_distribution(env: Env, distribution_id: u32) -> Result<i128, Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates the ed25519 public key that signs the claim vouchers.
+    ///
+    /// Removing the signer disables the redemption of vouchers.
+    ///
+    /// ## Arguments
+    ///
+    /// * `public_key` - The public key of the signer or `None` to remove it
+    fn update_voucher_signer(env: Env, public_key: Option<BytesN<32>>) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Updates whether the unused balances are distributed before the shares are updated.
+    ///
+    /// When enabled, `update_shares` distributes the unused balance of every whitelisted
+    /// token under the old shares, so deposits are split by the shares in force when they arrived.
+    ///
+    /// ## Arguments
+    ///
+    /// * `auto_distribute` - True to distribute the unused balances on share changes
+    fn update_auto_distribute(env: Env, auto_distribute: bool) -> Result<(), Error>;
+
     /// **ADMIN ONLY FUNCTION**
     ///
     /// Updates the shares of the shareholders.
     ///
     /// All of the shares and shareholders are updated on execution.
+    /// The token opt-outs are cleared, so shareholders have to opt out again under the new shares.
+    /// If auto distribution is enabled, the unused balances are distributed under the old shares first.
     ///
     /// ## Arguments
     ///
@@ -116,6 +264,41 @@ pub trait SplitterTrait {
         amount: i128,
     ) -> Result<(), Error>;
 
+    /// Claims the allocation of the recipient from a Merkle distribution.
+    ///
+    /// Each leaf of the Merkle tree can only be claimed once, until the distribution expires.
+    ///
+    /// ## Arguments
+    ///
+    /// * `distribution_id` - The ID of the Merkle distribution
+    /// * `index` - The index of the leaf in the Merkle tree
+    /// * `recipient` - The address of the recipient
+    /// * `amount` - The amount of tokens in the leaf
+    /// * `proof` - The sibling hashes from the leaf to the root
+    fn claim_merkle_allocation(
+        env: Env,
+        distribution_id: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error>;
+
+    /// Redeems a claim voucher signed by the voucher signer.
+    ///
+    /// The voucher is paid from the unused balance of the token. The signature
+    /// must be made over the XDR encoded voucher and each nonce can only be redeemed once.
+    ///
+    /// ## Arguments
+    ///
+    /// * `voucher` - The voucher to redeem
+    /// * `signature` - The ed25519 signature of the voucher
+    fn redeem_voucher(
+        env: Env,
+        voucher: VoucherDataKey,
+        signature: BytesN<64>,
+    ) -> Result<(), Error>;
+
     /// Withdraws the allocation of the current contract from another splitter contract.
     ///
     /// ## Arguments
@@ -130,6 +313,62 @@ pub trait SplitterTrait {
         amount: i128,
     ) -> Result<(), Error>;
 
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Creates or updates the recurring distribution schedule for a token.
+    ///
+    /// Scheduled distributions are executed by anyone calling `poke` once the interval
+    /// has elapsed. If the schedule already exists, the last distribution ledger is kept.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the whitelisted token to distribute
+    /// * `interval` - The minimum number of ledgers between two distributions
+    /// * `keeper_reward` - The keeper reward in basis points of the unused balance
+    fn update_distribution_schedule(
+        env: Env,
+        token_address: Address,
+        interval: u32,
+        keeper_reward: i128,
+    ) -> Result<(), Error>;
+
+    /// **ADMIN ONLY FUNCTION**
+    ///
+    /// Removes the recurring distribution schedule for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    fn remove_distribution_schedule(env: Env, token_address: Address) -> Result<(), Error>;
+
+    /// Executes the scheduled distribution for a token if the interval has elapsed.
+    ///
+    /// All of the unused balance is distributed to the shareholders after the
+    /// keeper reward is paid to the keeper.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token to distribute
+    /// * `keeper` - The address of the caller receiving the keeper reward
+    fn poke(env: Env, token_address: Address, keeper: Address) -> Result<(), Error>;
+
+    /// Opts the shareholder out of or back into the distributions of a token.
+    ///
+    /// The shares of the opted in shareholders are re-normalized to split the
+    /// whole distribution amount. Opt-outs are cleared when the shares are updated.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    /// * `opt_out` - True to opt out of the token, false to opt back in
+    fn update_token_opt_out(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        opt_out: bool,
+    ) -> Result<(), Error>;
+
     // ========== Query Functions ==========
 
     /// Gets the share of a shareholder.
@@ -150,13 +389,31 @@ pub trait SplitterTrait {
     /// * `Vec<ShareDataKey>` - The list of shareholders with their shares
     fn list_shares(env: Env) -> Result<Vec<ShareDataKey>, Error>;
 
+    /// Lists the tokens the shareholder opted out of.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<Address>` - The addresses of the tokens
+    fn list_opt_outs(env: Env, shareholder: Address) -> Result<Vec<Address>, Error>;
+
     /// Gets the contract configuration.
     ///
     /// ## Returns
     ///
-    /// * `ConfigDataKey` - The contract configuration
+    /// * `ConfigDataKey` - The contract configuration, including the protocol fee
     fn get_config(env: Env) -> Result<ConfigDataKey, Error>;
 
+    /// Gets whether the unused balances are distributed before the shares are updated.
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if auto distribution is enabled
+    fn get_auto_distribute(env: Env) -> Result<bool, Error>;
+
     /// Gets the allocation of a shareholder for a token.
     ///
     /// ## Arguments
@@ -179,6 +436,124 @@ pub trait SplitterTrait {
     /// * `i128` - The amount of unused tokens
     fn get_unused_tokens(env: Env, token_addres: Address) -> Result<i128, Error>;
 
+    /// Gets the balance accounting of a token.
+    ///
+    /// The shortfall shows the allocations and Merkle reservations that are not backed by the balance,
+    /// e.g. after a rebase or clawback. Withdrawals are prorated while there is a shortfall.
+    /// The balance loss is the total amount that left the contract outside of its operations.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `TokenBalanceDataKey` - The tracked balance, balance loss, actual balance, total allocation and shortfall
+    fn get_token_balance(env: Env, token_address: Address) -> Result<TokenBalanceDataKey, Error>;
+
+    /// Gets the lifetime totals of a shareholder for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `shareholder` - The address of the shareholder
+    /// * `token_address` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `LifetimeTotalsDataKey` - The total amounts distributed to and withdrawn by the shareholder
+    fn get_lifetime_totals(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error>;
+
+    /// Gets the lifetime totals of a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `LifetimeTotalsDataKey` - The total amounts distributed and withdrawn for the token
+    fn get_token_lifetime_totals(
+        env: Env,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error>;
+
+    /// Gets a distribution round.
+    ///
+    /// ## Arguments
+    ///
+    /// * `round_id` - The ID of the round
+    ///
+    /// ## Returns
+    ///
+    /// * `DistributionRoundDataKey` - The token, amount, ledger and share table hash of the round
+    fn get_distribution(env: Env, round_id: u32) -> Result<DistributionRoundDataKey, Error>;
+
+    /// Lists the distribution rounds of a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    /// * `from` - The index of the first round of the token to list
+    /// * `limit` - The maximum number of rounds to list, capped at 50
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<DistributionRoundDataKey>` - The distribution rounds of the token
+    fn list_distributions(
+        env: Env,
+        token_address: Address,
+        from: u32,
+        limit: u32,
+    ) -> Result<Vec<DistributionRoundDataKey>, Error>;
+
+    /// Gets a Merkle distribution.
+    ///
+    /// ## Arguments
+    ///
+    /// * `distribution_id` - The ID of the Merkle distribution
+    ///
+    /// ## Returns
+    ///
+    /// * `MerkleDistributionDataKey` - The token, root, total amount, claimed amount, expiry and status of the distribution
+    fn get_merkle_distribution(
+        env: Env,
+        distribution_id: u32,
+    ) -> Result<MerkleDistributionDataKey, Error>;
+
+    /// Checks if a leaf of a Merkle distribution has been claimed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `distribution_id` - The ID of the Merkle distribution
+    /// * `index` - The index of the leaf in the Merkle tree
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the leaf has been claimed
+    fn is_merkle_claimed(env: Env, distribution_id: u32, index: u32) -> Result<bool, Error>;
+
+    /// Gets the ed25519 public key that signs the claim vouchers.
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<BytesN<32>>` - The public key of the signer if it is set
+    fn get_voucher_signer(env: Env) -> Result<Option<BytesN<32>>, Error>;
+
+    /// Checks if a voucher with the nonce has been redeemed.
+    ///
+    /// ## Arguments
+    ///
+    /// * `nonce` - The nonce of the voucher
+    ///
+    /// ## Returns
+    ///
+    /// * `bool` - True if the voucher has been redeemed
+    fn is_voucher_redeemed(env: Env, nonce: u64) -> Result<bool, Error>;
+
     /// Lists the allocations of the shareholder.
     ///
     /// ## Arguments
@@ -194,8 +569,40 @@ pub trait SplitterTrait {
     ///
     /// ## Returns
     ///
-    /// * `Vec<Address>` - The list of whitelisted token addresses
-    fn list_whitelisted_tokens(env: Env) -> Result<Vec<Address>, Error>;
+    /// * `Vec<WhitelistedTokenDataKey>` - The list of whitelisted tokens with their minimum distribution amounts
+    fn list_whitelisted_tokens(env: Env) -> Result<Vec<WhitelistedTokenDataKey>, Error>;
+
+    /// Lists the metadata of the whitelisted tokens.
+    ///
+    /// The name, symbol and decimals are cached when the token is whitelisted.
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<TokenInfoDataKey>` - The list of whitelisted tokens with their metadata
+    fn list_whitelisted_token_info(env: Env) -> Result<Vec<TokenInfoDataKey>, Error>;
+
+    /// Lists the tokens that still have allocations with their total allocation.
+    ///
+    /// Tokens removed from the whitelist are listed until all allocations are withdrawn.
+    ///
+    /// ## Returns
+    ///
+    /// * `Vec<(Address, i128)>` - The list of tokens with their total allocation
+    fn list_tokens_with_allocations(env: Env) -> Result<Vec<(Address, i128)>, Error>;
+
+    /// Gets the recurring distribution schedule for a token.
+    ///
+    /// ## Arguments
+    ///
+    /// * `token_address` - The address of the token
+    ///
+    /// ## Returns
+    ///
+    /// * `Option<ScheduleDataKey>` - The distribution schedule if it exists
+    fn get_distribution_schedule(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Option<ScheduleDataKey>, Error>;
 }
 
 #[contract]
@@ -211,14 +618,35 @@ impl SplitterTrait for Splitter {
         name: Bytes,
         shares: Vec<ShareDataKey>,
         updatable: bool,
+        protocol_fee: Option<ProtocolFeeDataKey>,
     ) -> Result<(), Error> {
-        execute::init(env, admin, name, shares, updatable)
+        execute::init(env, admin, name, shares, updatable, protocol_fee)
     }
 
     fn update_whitelisted_tokens(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
         execute::update_whitelisted_tokens(env, tokens)
     }
 
+    fn add_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error> {
+        execute::add_whitelisted_token(env, token_address)
+    }
+
+    fn remove_whitelisted_token(env: Env, token_address: Address) -> Result<(), Error> {
+        execute::remove_whitelisted_token(env, token_address)
+    }
+
+    fn update_min_distribution(
+        env: Env,
+        token_address: Address,
+        min_distribution: i128,
+    ) -> Result<(), Error> {
+        execute::update_min_distribution(env, token_address, min_distribution)
+    }
+
+    fn reconcile_allocations(env: Env, token_address: Address) -> Result<(), Error> {
+        execute::reconcile_allocations(env, token_address)
+    }
+
     fn transfer_tokens(
         env: Env,
         token_address: Address,
@@ -232,6 +660,37 @@ impl SplitterTrait for Splitter {
         execute::distribute_tokens(env, token_address, amount)
     }
 
+    fn distribute_to_shareholders(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        shareholders: Vec<Address>,
+    ) -> Result<(), Error> {
+        execute::distribute_to_shareholders(env, token_address, amount, shareholders)
+    }
+
+    fn create_merkle_distribution(
+        env: Env,
+        token_address: Address,
+        amount: i128,
+        merkle_root: BytesN<32>,
+        expiry: u32,
+    ) -> Result<u32, Error> {
+        execute::create_merkle_distribution(env, token_address, amount, merkle_root, expiry)
+    }
+
+    fn close_merkle_distribution(env: Env, distribution_id: u32) -> Result<i128, Error> {
+        execute::close_merkle_distribution(env, distribution_id)
+    }
+
+    fn update_voucher_signer(env: Env, public_key: Option<BytesN<32>>) -> Result<(), Error> {
+        execute::update_voucher_signer(env, public_key)
+    }
+
+    fn update_auto_distribute(env: Env, auto_distribute: bool) -> Result<(), Error> {
+        execute::update_auto_distribute(env, auto_distribute)
+    }
+
     fn update_shares(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
         execute::update_shares(env, shares)
     }
@@ -253,6 +712,25 @@ impl SplitterTrait for Splitter {
         execute::withdraw_allocation(env, token_address, shareholder, amount)
     }
 
+    fn claim_merkle_allocation(
+        env: Env,
+        distribution_id: u32,
+        index: u32,
+        recipient: Address,
+        amount: i128,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<(), Error> {
+        execute::claim_merkle_allocation(env, distribution_id, index, recipient, amount, proof)
+    }
+
+    fn redeem_voucher(
+        env: Env,
+        voucher: VoucherDataKey,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        execute::redeem_voucher(env, voucher, signature)
+    }
+
     fn withdraw_external_allocation(
         env: Env,
         splitter_address: Address,
@@ -262,6 +740,32 @@ impl SplitterTrait for Splitter {
         execute::withdraw_external_allocation(env, splitter_address, token_address, amount)
     }
 
+    fn update_distribution_schedule(
+        env: Env,
+        token_address: Address,
+        interval: u32,
+        keeper_reward: i128,
+    ) -> Result<(), Error> {
+        execute::update_distribution_schedule(env, token_address, interval, keeper_reward)
+    }
+
+    fn remove_distribution_schedule(env: Env, token_address: Address) -> Result<(), Error> {
+        execute::remove_distribution_schedule(env, token_address)
+    }
+
+    fn poke(env: Env, token_address: Address, keeper: Address) -> Result<(), Error> {
+        execute::poke(env, token_address, keeper)
+    }
+
+    fn update_token_opt_out(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+        opt_out: bool,
+    ) -> Result<(), Error> {
+        execute::update_token_opt_out(env, shareholder, token_address, opt_out)
+    }
+
     // ========== Query Functions ==========
 
     fn get_share(env: Env, shareholder: Address) -> Result<Option<i128>, Error> {
@@ -272,10 +776,18 @@ impl SplitterTrait for Splitter {
         query::list_shares(env)
     }
 
+    fn list_opt_outs(env: Env, shareholder: Address) -> Result<Vec<Address>, Error> {
+        query::list_opt_outs(env, shareholder)
+    }
+
     fn get_config(env: Env) -> Result<ConfigDataKey, Error> {
         query::get_config(env)
     }
 
+    fn get_auto_distribute(env: Env) -> Result<bool, Error> {
+        query::get_auto_distribute(env)
+    }
+
     fn get_allocation(
         env: Env,
         shareholder: Address,
@@ -288,11 +800,77 @@ impl SplitterTrait for Splitter {
         query::get_unused_tokens(env, token_addres)
     }
 
+    fn get_token_balance(env: Env, token_address: Address) -> Result<TokenBalanceDataKey, Error> {
+        query::get_token_balance(env, token_address)
+    }
+
+    fn get_lifetime_totals(
+        env: Env,
+        shareholder: Address,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error> {
+        query::get_lifetime_totals(env, shareholder, token_address)
+    }
+
+    fn get_token_lifetime_totals(
+        env: Env,
+        token_address: Address,
+    ) -> Result<LifetimeTotalsDataKey, Error> {
+        query::get_token_lifetime_totals(env, token_address)
+    }
+
+    fn get_distribution(env: Env, round_id: u32) -> Result<DistributionRoundDataKey, Error> {
+        query::get_distribution(env, round_id)
+    }
+
+    fn list_distributions(
+        env: Env,
+        token_address: Address,
+        from: u32,
+        limit: u32,
+    ) -> Result<Vec<DistributionRoundDataKey>, Error> {
+        query::list_distributions(env, token_address, from, limit)
+    }
+
+    fn get_merkle_distribution(
+        env: Env,
+        distribution_id: u32,
+    ) -> Result<MerkleDistributionDataKey, Error> {
+        query::get_merkle_distribution(env, distribution_id)
+    }
+
+    fn is_merkle_claimed(env: Env, distribution_id: u32, index: u32) -> Result<bool, Error> {
+        query::is_merkle_claimed(env, distribution_id, index)
+    }
+
+    fn get_voucher_signer(env: Env) -> Result<Option<BytesN<32>>, Error> {
+        query::get_voucher_signer(env)
+    }
+
+    fn is_voucher_redeemed(env: Env, nonce: u64) -> Result<bool, Error> {
+        query::is_voucher_redeemed(env, nonce)
+    }
+
     // fn list_allocations(env: Env, shareholder: Address) -> Result<Vec<(Address, i128)>, Error> {
     //     query::list_allocations(env, shareholder)
     // }
 
-    fn list_whitelisted_tokens(env: Env) -> Result<Vec<Address>, Error> {
+    fn list_whitelisted_tokens(env: Env) -> Result<Vec<WhitelistedTokenDataKey>, Error> {
         query::list_whitelisted_tokens(env)
     }
+
+    fn list_whitelisted_token_info(env: Env) -> Result<Vec<TokenInfoDataKey>, Error> {
+        query::list_whitelisted_token_info(env)
+    }
+
+    fn list_tokens_with_allocations(env: Env) -> Result<Vec<(Address, i128)>, Error> {
+        query::list_tokens_with_allocations(env)
+    }
+
+    fn get_distribution_schedule(
+        env: Env,
+        token_address: Address,
+    ) -> Result<Option<ScheduleDataKey>, Error> {
+        query::get_distribution_schedule(env, token_address)
+    }
 }
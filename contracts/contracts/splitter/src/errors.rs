@@ -1,4 +1,5 @@
 use soroban_sdk::contracterror;
+use sorosplits_utils::token::TokenError;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -22,4 +23,57 @@ pub enum Error {
     WithdrawalAmountAboveAllocation = 113,
     // Token whitelist errors
     TokenNotWhitelisted = 114,
+    // Distribution schedule errors
+    InvalidScheduleInterval = 115,
+    InvalidKeeperReward = 116,
+    ScheduleNotFound = 117,
+    ScheduleIntervalNotElapsed = 118,
+    // Minimum distribution errors
+    InvalidMinDistribution = 119,
+    DistributionAmountBelowMinimum = 120,
+    // Protocol fee errors
+    InvalidProtocolFee = 121,
+    // Token whitelist management errors
+    TokenAlreadyWhitelisted = 122,
+    WhitelistLimitReached = 123,
+    TokenHasAllocations = 124,
+    // Token validation errors
+    InvalidToken = 125,
+    // Token transfer errors
+    TokenTransferUnauthorized = 126,
+    TokenTransferFailed = 127,
+    // Allocation reconciliation errors
+    NoAllocationShortfall = 128,
+    // Distribution round errors
+    RoundNotFound = 129,
+    // Merkle distribution errors
+    MerkleDistributionNotFound = 130,
+    InvalidMerkleProof = 131,
+    MerkleAllocationAlreadyClaimed = 132,
+    // Claim voucher errors
+    VoucherSignerNotSet = 133,
+    VoucherExpired = 134,
+    VoucherAlreadyRedeemed = 135,
+    InvalidVoucher = 136,
+    // Token opt-out errors
+    NotShareholder = 137,
+    NoOptedInShareholders = 138,
+    // Merkle distribution lifecycle errors
+    InvalidMerkleExpiry = 139,
+    MerkleDistributionExpired = 140,
+    MerkleDistributionNotExpired = 141,
+    MerkleDistributionClosed = 142,
+    MerkleDistributionExhausted = 143,
+    // Shareholder subset errors
+    DuplicateShareholder = 144,
+}
+
+impl From<TokenError> for Error {
+    fn from(error: TokenError) -> Self {
+        match error {
+            TokenError::TransferUnauthorized => Error::TokenTransferUnauthorized,
+            TokenError::TransferFailed => Error::TokenTransferFailed,
+            _ => Error::InvalidToken,
+        }
+    }
 }
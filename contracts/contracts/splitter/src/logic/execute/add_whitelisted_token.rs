@@ -0,0 +1,33 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::get_token_info,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{WhitelistedTokens, MAX_WHITELISTED_TOKENS},
+    },
+};
+
+pub fn execute(env: Env, token_address: Address) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    // Check if token address is valid and read its metadata
+    let token_info = get_token_info(&env, &token_address)?;
+
+    // The token can only be whitelisted once
+    if WhitelistedTokens::check_token_address(&env, &token_address) {
+        return Err(Error::TokenAlreadyWhitelisted);
+    }
+
+    // Keep the list under the maximum size
+    if WhitelistedTokens::get_list(&env).len() >= MAX_WHITELISTED_TOKENS {
+        return Err(Error::WhitelistLimitReached);
+    }
+
+    WhitelistedTokens::add(&env, &token_address);
+    WhitelistedTokens::save_token_info(&env, &token_info);
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use soroban_sdk::{Address, BytesN, Env, Vec};
+
+use sorosplits_utils::token::try_transfer;
+
+use crate::{
+    errors::Error,
+    logic::helpers::{
+        get_merkle_leaf, get_prorated_amount, sync_tracked_balance, verify_merkle_proof,
+    },
+    storage::{
+        config::ConfigDataKey, history::LifetimeTotalsDataKey, merkle::MerkleDistributionDataKey,
+    },
+};
+
+pub fn execute(
+    env: Env,
+    distribution_id: u32,
+    index: u32,
+    recipient: Address,
+    amount: i128,
+    proof: Vec<BytesN<32>>,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the recipient
+    recipient.require_auth();
+
+    let mut distribution = match MerkleDistributionDataKey::get(&env, distribution_id) {
+        Some(distribution) => distribution,
+        None => return Err(Error::MerkleDistributionNotFound),
+    };
+
+    if distribution.closed {
+        return Err(Error::MerkleDistributionClosed);
+    }
+    if env.ledger().sequence() > distribution.expiry {
+        return Err(Error::MerkleDistributionExpired);
+    }
+
+    if MerkleDistributionDataKey::is_claimed(&env, distribution_id, index) {
+        return Err(Error::MerkleAllocationAlreadyClaimed);
+    }
+
+    // Claim amount cannot be equal and less than 0
+    if amount <= 0 {
+        return Err(Error::ZeroWithdrawalAmount);
+    }
+
+    let leaf = get_merkle_leaf(&env, index, &recipient, amount);
+    if !verify_merkle_proof(&env, &distribution.root, leaf, &proof) {
+        return Err(Error::InvalidMerkleProof);
+    }
+    // A valid proof can't claim more than the remaining amount of a malformed tree
+    if distribution.claimed + amount > distribution.amount {
+        return Err(Error::MerkleDistributionExhausted);
+    }
+
+    // Claims are prorated the same way as withdrawals if the balance can't cover
+    // the allocations and the reserved amounts
+    let payout = get_prorated_amount(
+        &env,
+        &distribution.token,
+        distribution.get_claim_reserve(amount),
+    );
+
+    distribution.claim(&env, index, amount);
+
+    // Transfer the tokens to the recipient
+    if payout > 0 {
+        try_transfer(&env, &distribution.token, &recipient, &payout)?;
+    }
+    LifetimeTotalsDataKey::add_distributed(&env, &recipient, &distribution.token, amount);
+    LifetimeTotalsDataKey::add_withdrawn(&env, &recipient, &distribution.token, payout);
+    // The haircut of a prorated claim is written off
+    if payout < amount {
+        LifetimeTotalsDataKey::add_written_off(
+            &env,
+            &recipient,
+            &distribution.token,
+            amount - payout,
+        );
+    }
+    sync_tracked_balance(&env, &distribution.token, payout);
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, merkle::MerkleDistributionDataKey},
+};
+
+pub fn execute(env: Env, distribution_id: u32) -> Result<i128, Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    let mut distribution = match MerkleDistributionDataKey::get(&env, distribution_id) {
+        Some(distribution) => distribution,
+        None => return Err(Error::MerkleDistributionNotFound),
+    };
+
+    if distribution.closed {
+        return Err(Error::MerkleDistributionClosed);
+    }
+    // Recipients can claim their allocations until the distribution expires
+    if env.ledger().sequence() <= distribution.expiry {
+        return Err(Error::MerkleDistributionNotExpired);
+    }
+
+    // Release the unclaimed amount back to the unused balance
+    Ok(distribution.close(&env))
+}
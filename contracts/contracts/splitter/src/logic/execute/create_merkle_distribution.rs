@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{
+        allocate_protocol_fee, get_protocol_fee_amount, get_unused_balance, sync_tracked_balance,
+    },
+    storage::{
+        config::ConfigDataKey, distributions::WhitelistedTokens, merkle::MerkleDistributionDataKey,
+    },
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    amount: i128,
+    merkle_root: BytesN<32>,
+    expiry: u32,
+) -> Result<u32, Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    if !WhitelistedTokens::check_token_address(&env, &token_address) {
+        return Err(Error::TokenNotWhitelisted);
+    }
+
+    // Check for amount errors
+    if amount <= 0 {
+        return Err(Error::ZeroTransferAmount);
+    }
+    // The allocations have to be claimable for at least the current ledger
+    if expiry < env.ledger().sequence() {
+        return Err(Error::InvalidMerkleExpiry);
+    }
+    // The protocol fee is taken on top of the amount, the leaves commit to exact amounts
+    let fee_amount = get_protocol_fee_amount(&env, amount);
+
+    // Tokens that are already allocated or reserved cannot be reserved again
+    if amount + fee_amount > get_unused_balance(&env, &token_address) {
+        return Err(Error::InsufficientBalance);
+    }
+
+    sync_tracked_balance(&env, &token_address, 0);
+    allocate_protocol_fee(&env, &token_address, fee_amount);
+
+    // Reserve the amount for the recipients in the Merkle tree
+    Ok(MerkleDistributionDataKey::create(
+        &env,
+        &token_address,
+        &merkle_root,
+        amount,
+        expiry,
+    ))
+}
@@ -0,0 +1,34 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    logic::helpers::{check_distribution, distribute_tokens_to},
+    storage::{config::ConfigDataKey, recipients::RecipientKeys},
+};
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    amount: i128,
+    shareholders: Vec<Address>,
+) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    for (index, shareholder) in shareholders.iter().enumerate() {
+        if RecipientKeys::get_share(&env, &shareholder).is_none() {
+            return Err(Error::NotShareholder);
+        }
+        // A duplicate shareholder would receive its share more than once
+        if shareholders.first_index_of(&shareholder) != Some(index as u32) {
+            return Err(Error::DuplicateShareholder);
+        }
+    }
+
+    check_distribution(&env, &token_address, amount)?;
+
+    // Allocate the amount to the given shareholders
+    distribute_tokens_to(&env, &token_address, amount, &shareholders)?;
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::{Address, Bytes, Env, Vec};
-use sorosplits_utils::types::ShareDataKey;
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
 
 use crate::{
     errors::Error,
@@ -13,13 +13,21 @@ pub fn execute(
     name: Bytes,
     shares: Vec<ShareDataKey>,
     updatable: bool,
+    protocol_fee: Option<ProtocolFeeDataKey>,
 ) -> Result<(), Error> {
     if ConfigDataKey::exists(&env) {
         return Err(Error::AlreadyInitialized);
     };
 
+    // Protocol fee cannot be negative or above the maximum fee
+    if let Some(protocol_fee) = &protocol_fee {
+        if !protocol_fee.is_valid() {
+            return Err(Error::InvalidProtocolFee);
+        }
+    };
+
     // Initialize the contract configuration
-    ConfigDataKey::init(&env, admin, name, updatable);
+    ConfigDataKey::init(&env, admin, name, updatable, protocol_fee);
 
     // Check if the shares sum up to 10000
     check_shares(&shares)?;
@@ -1,19 +1,49 @@
+mod add_whitelisted_token;
+mod claim_merkle_allocation;
+mod close_merkle_distribution;
+mod create_merkle_distribution;
+mod distribute_to_shareholders;
 mod distribute_tokens;
 mod init;
 mod lock_contract;
+mod poke;
+mod reconcile_allocations;
+mod redeem_voucher;
+mod remove_distribution_schedule;
+mod remove_whitelisted_token;
 mod transfer_tokens;
+mod update_auto_distribute;
+mod update_distribution_schedule;
+mod update_min_distribution;
 mod update_name;
 mod update_shares;
+mod update_token_opt_out;
+mod update_voucher_signer;
 mod update_whitelisted_tokens;
 mod withdraw_allocation;
 mod withdraw_external_allocation;
 
+pub use add_whitelisted_token::execute as add_whitelisted_token;
+pub use claim_merkle_allocation::execute as claim_merkle_allocation;
+pub use close_merkle_distribution::execute as close_merkle_distribution;
+pub use create_merkle_distribution::execute as create_merkle_distribution;
+pub use distribute_to_shareholders::execute as distribute_to_shareholders;
 pub use distribute_tokens::execute as distribute_tokens;
 pub use init::execute as init;
 pub use lock_contract::execute as lock_contract;
+pub use poke::execute as poke;
+pub use reconcile_allocations::execute as reconcile_allocations;
+pub use redeem_voucher::execute as redeem_voucher;
+pub use remove_distribution_schedule::execute as remove_distribution_schedule;
+pub use remove_whitelisted_token::execute as remove_whitelisted_token;
 pub use transfer_tokens::execute as transfer_tokens;
+pub use update_auto_distribute::execute as update_auto_distribute;
+pub use update_distribution_schedule::execute as update_distribution_schedule;
+pub use update_min_distribution::execute as update_min_distribution;
 pub use update_name::execute as update_name;
 pub use update_shares::execute as update_shares;
+pub use update_token_opt_out::execute as update_token_opt_out;
+pub use update_voucher_signer::execute as update_voucher_signer;
 pub use update_whitelisted_tokens::execute as update_whitelisted_tokens;
 pub use withdraw_allocation::execute as withdraw_allocation;
 pub use withdraw_external_allocation::execute as withdraw_external_allocation;
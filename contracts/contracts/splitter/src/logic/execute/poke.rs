@@ -0,0 +1,65 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{Address, Env};
+
+use sorosplits_utils::token::try_transfer;
+
+use crate::{
+    errors::Error,
+    logic::helpers::{distribute_tokens, get_unused_balance, sync_tracked_balance},
+    storage::{
+        config::ConfigDataKey, distributions::WhitelistedTokens, schedules::ScheduleDataKey,
+    },
+};
+
+pub fn execute(env: Env, token_address: Address, keeper: Address) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the keeper
+    keeper.require_auth();
+
+    let mut schedule = match ScheduleDataKey::get(&env, &token_address) {
+        Some(schedule) => schedule,
+        None => return Err(Error::ScheduleNotFound),
+    };
+
+    let whitelisted_token = match WhitelistedTokens::get_token(&env, &token_address) {
+        Some(whitelisted_token) => whitelisted_token,
+        None => return Err(Error::TokenNotWhitelisted),
+    };
+
+    // Make sure enough ledgers have passed since the last distribution
+    if !schedule.is_due(&env) {
+        return Err(Error::ScheduleIntervalNotElapsed);
+    };
+
+    // Only the unused balance is distributed
+    let unused_balance = get_unused_balance(&env, &token_address);
+    if unused_balance <= 0 {
+        return Err(Error::ZeroTransferAmount);
+    };
+
+    // Keeper reward is taken from the unused balance before the distribution
+    let reward = unused_balance
+        .fixed_mul_floor(schedule.keeper_reward, 10000)
+        .unwrap_or(0);
+
+    // Allocate the rest of the unused balance to the shareholders
+    let amount = unused_balance - reward;
+    if amount < whitelisted_token.min_distribution {
+        return Err(Error::DistributionAmountBelowMinimum);
+    };
+    distribute_tokens(&env, &token_address, amount)?;
+
+    schedule.last_distribution = env.ledger().sequence();
+    ScheduleDataKey::save(&env, &token_address, &schedule);
+
+    // Pay the keeper reward
+    if reward > 0 {
+        try_transfer(&env, &token_address, &keeper, &reward)?;
+    }
+    sync_tracked_balance(&env, &token_address, reward);
+
+    Ok(())
+}
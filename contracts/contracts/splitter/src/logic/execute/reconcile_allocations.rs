@@ -0,0 +1,68 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{Address, Env};
+
+use sorosplits_utils::token::get_token_balance;
+
+use crate::{
+    errors::Error,
+    logic::helpers::sync_tracked_balance,
+    storage::{
+        config::ConfigDataKey, distributions::TokenAllocations, history::LifetimeTotalsDataKey,
+        merkle::MerkleDistributionDataKey,
+    },
+};
+
+pub fn execute(env: Env, token_address: Address) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    let balance = get_token_balance(&env, &token_address, &env.current_contract_address()).max(0);
+    let total_allocation = TokenAllocations::get_total(&env, &token_address).unwrap_or(0);
+    let total_claims =
+        total_allocation + MerkleDistributionDataKey::get_reserved(&env, &token_address);
+
+    // Nothing to reconcile if the balance covers all the allocations and the reserved amounts
+    if balance >= total_claims {
+        return Err(Error::NoAllocationShortfall);
+    };
+
+    // Scale down every allocation by the ratio of the balance to the total claims
+    for holder in TokenAllocations::get_holders(&env, &token_address).iter() {
+        let allocation = TokenAllocations::get(&env, &holder, &token_address).unwrap_or(0);
+        let new_allocation = allocation
+            .fixed_mul_floor(balance, total_claims)
+            .unwrap_or(0);
+
+        if new_allocation > 0 {
+            TokenAllocations::save(&env, &holder, &token_address, new_allocation);
+        } else {
+            TokenAllocations::remove(&env, &holder, &token_address);
+        }
+
+        // Record the reduction so the lifetime totals still add up to the allocation
+        LifetimeTotalsDataKey::add_written_off(
+            &env,
+            &holder,
+            &token_address,
+            allocation - new_allocation,
+        );
+    }
+
+    // Scale down the reserved amounts of the open Merkle distributions by the same ratio
+    for id in 1..=MerkleDistributionDataKey::get_count(&env) {
+        if let Some(mut distribution) = MerkleDistributionDataKey::get(&env, id) {
+            if distribution.token != token_address || distribution.closed {
+                continue;
+            }
+            let reserved = distribution
+                .reserved
+                .fixed_mul_floor(balance, total_claims)
+                .unwrap_or(0);
+            distribution.write_down(&env, reserved);
+        }
+    }
+
+    sync_tracked_balance(&env, &token_address, 0);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+use soroban_sdk::{xdr::ToXdr, BytesN, Env};
+
+use sorosplits_utils::token::try_transfer;
+
+use crate::{
+    errors::Error,
+    logic::helpers::{
+        allocate_protocol_fee, get_protocol_fee_amount, get_unused_balance, sync_tracked_balance,
+    },
+    storage::{
+        config::ConfigDataKey, distributions::WhitelistedTokens, history::LifetimeTotalsDataKey,
+        vouchers::VoucherDataKey,
+    },
+};
+
+pub fn execute(env: Env, voucher: VoucherDataKey, signature: BytesN<64>) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the recipient
+    voucher.recipient.require_auth();
+
+    let signer = match VoucherDataKey::get_signer(&env) {
+        Some(signer) => signer,
+        None => return Err(Error::VoucherSignerNotSet),
+    };
+
+    // Vouchers signed for another contract cannot be redeemed
+    if voucher.contract != env.current_contract_address() {
+        return Err(Error::InvalidVoucher);
+    }
+    if env.ledger().sequence() > voucher.expiry {
+        return Err(Error::VoucherExpired);
+    }
+    if VoucherDataKey::is_redeemed(&env, voucher.nonce) {
+        return Err(Error::VoucherAlreadyRedeemed);
+    }
+
+    // Voucher amount cannot be equal and less than 0
+    if voucher.amount <= 0 {
+        return Err(Error::ZeroTransferAmount);
+    }
+    if !WhitelistedTokens::check_token_address(&env, &voucher.token) {
+        return Err(Error::TokenNotWhitelisted);
+    }
+
+    // Traps if the signature doesn't match the voucher
+    env.crypto()
+        .ed25519_verify(&signer, &voucher.clone().to_xdr(&env), &signature);
+
+    // Vouchers are paid from the unused balance, the protocol fee is taken on top
+    // of the signed amount
+    let fee_amount = get_protocol_fee_amount(&env, voucher.amount);
+    if voucher.amount + fee_amount > get_unused_balance(&env, &voucher.token) {
+        return Err(Error::InsufficientBalance);
+    }
+
+    VoucherDataKey::save_redeemed(&env, voucher.nonce);
+    allocate_protocol_fee(&env, &voucher.token, fee_amount);
+
+    // Transfer the tokens to the recipient
+    try_transfer(&env, &voucher.token, &voucher.recipient, &voucher.amount)?;
+    LifetimeTotalsDataKey::add_distributed(
+        &env,
+        &voucher.recipient,
+        &voucher.token,
+        voucher.amount,
+    );
+    LifetimeTotalsDataKey::add_withdrawn(&env, &voucher.recipient, &voucher.token, voucher.amount);
+    sync_tracked_balance(&env, &voucher.token, voucher.amount);
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, schedules::ScheduleDataKey},
+};
+
+pub fn execute(env: Env, token_address: Address) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    if ScheduleDataKey::get(&env, &token_address).is_none() {
+        return Err(Error::ScheduleNotFound);
+    };
+
+    ScheduleDataKey::remove(&env, &token_address);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenAllocations, WhitelistedTokens},
+    },
+};
+
+pub fn execute(env: Env, token_address: Address) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    if !WhitelistedTokens::check_token_address(&env, &token_address) {
+        return Err(Error::TokenNotWhitelisted);
+    }
+
+    // Shareholders should be able to withdraw their allocations before the token is removed
+    if TokenAllocations::get_total(&env, &token_address).unwrap_or(0) != 0 {
+        return Err(Error::TokenHasAllocations);
+    }
+
+    WhitelistedTokens::remove(&env, &token_address);
+    WhitelistedTokens::remove_token_info(&env, &token_address);
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use soroban_sdk::{Address, Env};
 
-use sorosplits_utils::token::get_token_client;
+use sorosplits_utils::token::{get_token_client, try_transfer};
 
 use crate::{
     errors::Error,
-    storage::{config::ConfigDataKey, distributions::TokenAllocations},
+    logic::helpers::{get_unused_balance, sync_tracked_balance},
+    storage::config::ConfigDataKey,
 };
 
 pub fn execute(
@@ -21,11 +22,8 @@ pub fn execute(
     // Get the available token balance
     let balance = token_client.balance(&env.current_contract_address());
 
-    // Get the total allocation for the token
-    let total_allocation = TokenAllocations::get_total(&env, &token_address).unwrap_or(0);
-
     // Calculate the unused balance that can be transferred
-    let unused_balance = balance - total_allocation;
+    let unused_balance = get_unused_balance(&env, &token_address);
 
     // Transfer amount cannot be equal and less than 0
     if amount <= 0 {
@@ -41,7 +39,8 @@ pub fn execute(
     };
 
     // Transfer the tokens to the recipient
-    token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+    try_transfer(&env, &token_address, &recipient, &amount)?;
+    sync_tracked_balance(&env, &token_address, amount);
 
     Ok(())
 }
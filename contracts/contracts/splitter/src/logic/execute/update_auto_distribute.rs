@@ -0,0 +1,12 @@
+use soroban_sdk::Env;
+
+use crate::{errors::Error, storage::config::ConfigDataKey};
+
+pub fn execute(env: Env, auto_distribute: bool) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    ConfigDataKey::save_auto_distribute(&env, auto_distribute);
+
+    Ok(())
+}
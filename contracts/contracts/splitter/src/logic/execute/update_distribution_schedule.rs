@@ -0,0 +1,52 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{
+        config::ConfigDataKey, distributions::WhitelistedTokens, schedules::ScheduleDataKey,
+    },
+};
+
+/// Maximum keeper reward in basis points (1%)
+const MAX_KEEPER_REWARD: i128 = 100;
+
+pub fn execute(
+    env: Env,
+    token_address: Address,
+    interval: u32,
+    keeper_reward: i128,
+) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    if !WhitelistedTokens::check_token_address(&env, &token_address) {
+        return Err(Error::TokenNotWhitelisted);
+    };
+
+    // Interval cannot be 0 ledgers
+    if interval == 0 {
+        return Err(Error::InvalidScheduleInterval);
+    };
+    // Keeper reward has to be between 0 and the maximum reward
+    if !(0..=MAX_KEEPER_REWARD).contains(&keeper_reward) {
+        return Err(Error::InvalidKeeperReward);
+    };
+
+    // Keep the last distribution ledger if the schedule already exists
+    let last_distribution = match ScheduleDataKey::get(&env, &token_address) {
+        Some(schedule) => schedule.last_distribution,
+        None => env.ledger().sequence(),
+    };
+
+    ScheduleDataKey::save(
+        &env,
+        &token_address,
+        &ScheduleDataKey {
+            interval,
+            keeper_reward,
+            last_distribution,
+        },
+    );
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, distributions::WhitelistedTokens},
+};
+
+pub fn execute(env: Env, token_address: Address, min_distribution: i128) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    if !WhitelistedTokens::check_token_address(&env, &token_address) {
+        return Err(Error::TokenNotWhitelisted);
+    };
+
+    // Minimum distribution amount cannot be negative
+    if min_distribution < 0 {
+        return Err(Error::InvalidMinDistribution);
+    };
+
+    WhitelistedTokens::update_min_distribution(&env, &token_address, min_distribution);
+
+    Ok(())
+}
@@ -3,8 +3,11 @@ use sorosplits_utils::types::ShareDataKey;
 
 use crate::{
     errors::Error,
-    logic::helpers::{check_shares, reset_shares, update_shares as update_shares_helper},
-    storage::config::ConfigDataKey,
+    logic::helpers::{
+        check_shares, distribute_tokens, get_unused_balance, reset_shares,
+        update_shares as update_shares_helper,
+    },
+    storage::{config::ConfigDataKey, distributions::WhitelistedTokens},
 };
 
 pub fn execute(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
@@ -21,8 +24,25 @@ pub fn execute(env: Env, shares: Vec<ShareDataKey>) -> Result<(), Error> {
     // Check if the shares sum up to 10000
     check_shares(&shares)?;
 
+    // Split the deposits by the shares in force when they arrived
+    if ConfigDataKey::get_auto_distribute(&env) {
+        for whitelisted_token in WhitelistedTokens::get_list(&env).iter() {
+            // The minimum distribution amount is not applied
+            // so that no deposit is left for the new shares
+            let unused_balance = get_unused_balance(&env, &whitelisted_token.token);
+            if unused_balance <= 0 {
+                continue;
+            }
+            match distribute_tokens(&env, &whitelisted_token.token, unused_balance) {
+                // Tokens that every shareholder opted out of are left for the new shares
+                Ok(_) | Err(Error::NoOptedInShareholders) => (),
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
     // Remove all of the shareholders and their shares
-    reset_shares(&env);
+    reset_shares(&env, &shares);
 
     // Update the shares of the shareholders
     update_shares_helper(&env, &shares)?;
@@ -0,0 +1,39 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, recipients::RecipientKeys},
+};
+
+pub fn execute(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+    opt_out: bool,
+) -> Result<(), Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Make sure the caller is the shareholder
+    shareholder.require_auth();
+
+    if RecipientKeys::get_share(&env, &shareholder).is_none() {
+        return Err(Error::NotShareholder);
+    }
+
+    let mut tokens = RecipientKeys::get_opt_outs(&env, &shareholder);
+    match tokens.first_index_of(&token_address) {
+        Some(index) if !opt_out => {
+            tokens.remove(index);
+        }
+        None if opt_out => {
+            tokens.push_back(token_address);
+        }
+        // Nothing to update if the shareholder is already in the requested state
+        _ => return Ok(()),
+    }
+    RecipientKeys::save_opt_outs(&env, &shareholder, &tokens);
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use soroban_sdk::{BytesN, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, vouchers::VoucherDataKey},
+};
+
+pub fn execute(env: Env, public_key: Option<BytesN<32>>) -> Result<(), Error> {
+    // Make sure the caller is the admin
+    ConfigDataKey::get(&env)?.require_admin();
+
+    // Removing the signer disables the redemption of vouchers
+    match public_key {
+        Some(public_key) => VoucherDataKey::save_signer(&env, &public_key),
+        None => VoucherDataKey::remove_signer(&env),
+    }
+
+    Ok(())
+}
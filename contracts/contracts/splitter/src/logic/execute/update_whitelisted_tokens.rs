@@ -1,19 +1,42 @@
 use soroban_sdk::{Address, Env, Vec};
 
-use sorosplits_utils::token::check_token;
-
 use crate::{
     errors::Error,
-    storage::{config::ConfigDataKey, distributions::WhitelistedTokens},
+    logic::helpers::get_token_info,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenInfoDataKey, WhitelistedTokens, MAX_WHITELISTED_TOKENS},
+    },
 };
 
 pub fn execute(env: Env, tokens: Vec<Address>) -> Result<(), Error> {
     // Make sure the caller is the admin
     ConfigDataKey::get(&env)?.require_admin();
 
-    // Check if token address is valid
-    for token_address in tokens.iter() {
-        check_token(&env, &token_address);
+    // Keep the list under the maximum size
+    if tokens.len() > MAX_WHITELISTED_TOKENS {
+        return Err(Error::WhitelistLimitReached);
+    }
+
+    let mut token_infos: Vec<TokenInfoDataKey> = Vec::new(&env);
+    for (index, token_address) in tokens.iter().enumerate() {
+        // Check if token address is valid and read its metadata
+        token_infos.push_back(get_token_info(&env, &token_address)?);
+
+        // Check for duplicate tokens
+        if tokens.first_index_of(&token_address) != Some(index as u32) {
+            return Err(Error::TokenAlreadyWhitelisted);
+        }
+    }
+
+    // Remove the cached metadata of the tokens that are dropped from the list
+    for whitelisted_token in WhitelistedTokens::get_list(&env).iter() {
+        if !tokens.contains(&whitelisted_token.token) {
+            WhitelistedTokens::remove_token_info(&env, &whitelisted_token.token);
+        }
+    }
+    for token_info in token_infos.iter() {
+        WhitelistedTokens::save_token_info(&env, &token_info);
     }
 
     // Update the whitelisted tokens list
@@ -1,10 +1,13 @@
 use soroban_sdk::{Address, Env};
 
-use sorosplits_utils::token::get_token_client;
+use sorosplits_utils::token::try_transfer;
 
 use crate::{
     errors::Error,
-    storage::{config::ConfigDataKey, distributions::TokenAllocations},
+    logic::helpers::{get_prorated_amount, sync_tracked_balance},
+    storage::{
+        config::ConfigDataKey, distributions::TokenAllocations, history::LifetimeTotalsDataKey,
+    },
 };
 
 pub fn execute(
@@ -20,13 +23,11 @@ pub fn execute(
     // Make sure the caller is the shareholder
     shareholder.require_auth();
 
-    let token_client = get_token_client(&env, &token_address);
-
     // Get the current allocation for the user - default to 0
     let allocation = TokenAllocations::get(&env, &shareholder, &token_address).unwrap_or(0);
 
     // Withdraw amount cannot be equal and less than 0
-    if amount == 0 {
+    if amount <= 0 {
         return Err(Error::ZeroWithdrawalAmount);
     };
     // Withdraw amount cannot be greater than the allocation
@@ -34,6 +35,9 @@ pub fn execute(
         return Err(Error::WithdrawalAmountAboveAllocation);
     };
 
+    // Prorate the withdrawal if the balance can't cover all the allocations
+    let payout = get_prorated_amount(&env, &token_address, amount);
+
     if amount == allocation {
         TokenAllocations::remove(&env, &shareholder, &token_address);
     } else {
@@ -41,7 +45,15 @@ pub fn execute(
     }
 
     // Transfer the tokens to the shareholder
-    token_client.transfer(&env.current_contract_address(), &shareholder, &amount);
+    if payout > 0 {
+        try_transfer(&env, &token_address, &shareholder, &payout)?;
+        LifetimeTotalsDataKey::add_withdrawn(&env, &shareholder, &token_address, payout);
+    }
+    // The haircut of a prorated withdrawal is written off
+    if payout < amount {
+        LifetimeTotalsDataKey::add_written_off(&env, &shareholder, &token_address, amount - payout);
+    }
+    sync_tracked_balance(&env, &token_address, payout);
 
     Ok(())
 }
@@ -1,7 +1,21 @@
-use soroban_sdk::{Address, Env, Vec};
-use sorosplits_utils::types::ShareDataKey;
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+use sorosplits_utils::{
+    token::{get_token_balance, validate_token, TokenKind},
+    types::ShareDataKey,
+};
 
-use crate::{errors::Error, storage::recipients::RecipientKeys};
+use crate::{
+    errors::Error,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenAllocations, TokenInfoDataKey, WhitelistedTokens},
+        history::LifetimeTotalsDataKey,
+        merkle::MerkleDistributionDataKey,
+        recipients::RecipientKeys,
+        rounds::DistributionRoundDataKey,
+    },
+};
 
 /// Checks if the shares sum up to 10000
 pub fn check_shares(shares: &Vec<ShareDataKey>) -> Result<(), Error> {
@@ -41,10 +55,233 @@ pub fn update_shares(env: &Env, shares: &Vec<ShareDataKey>) -> Result<(), Error>
     Ok(())
 }
 
-/// Removes all of the shareholders and their shares
-pub fn reset_shares(env: &Env) {
+/// Removes all of the shareholders with their shares
+///
+/// The token opt-outs are only removed for the shareholders that are not in the new shares.
+pub fn reset_shares(env: &Env, new_shares: &Vec<ShareDataKey>) {
     for shareholder in RecipientKeys::get_shareholders(env).iter() {
         RecipientKeys::remove_share(env, &shareholder);
+        if !new_shares
+            .iter()
+            .any(|share| share.shareholder == shareholder)
+        {
+            RecipientKeys::remove_opt_outs(env, &shareholder);
+        }
     }
     RecipientKeys::remove_shareholders(env);
 }
+
+/// Validates the token and returns its metadata to be cached in the whitelist
+pub fn get_token_info(env: &Env, token_address: &Address) -> Result<TokenInfoDataKey, Error> {
+    let metadata = validate_token(env, token_address)?;
+    Ok(TokenInfoDataKey {
+        token: token_address.clone(),
+        name: metadata.name,
+        symbol: metadata.symbol,
+        decimals: metadata.decimals,
+        is_stellar_asset: metadata.kind == TokenKind::StellarAsset,
+    })
+}
+
+/// Returns the token balance that is not allocated to any shareholder
+/// or reserved for the unclaimed Merkle allocations
+pub fn get_unused_balance(env: &Env, token_address: &Address) -> i128 {
+    let balance = get_token_balance(env, token_address, &env.current_contract_address());
+    let total_allocation = TokenAllocations::get_total(env, token_address).unwrap_or(0);
+    let reserved = MerkleDistributionDataKey::get_reserved(env, token_address);
+    balance - total_allocation - reserved
+}
+
+/// Records the current token balance of the contract and returns it
+///
+/// Called after every operation that moves tokens, with the amount the operation
+/// transferred out. If the balance is lower than the last recorded balance minus that
+/// amount, tokens left the contract without going through it (e.g. rebasing or clawback)
+/// and the difference is recorded as a balance loss.
+pub fn sync_tracked_balance(env: &Env, token_address: &Address, outflow: i128) -> i128 {
+    let balance = get_token_balance(env, token_address, &env.current_contract_address());
+    if let Some(tracked_balance) = TokenAllocations::get_tracked_balance(env, token_address) {
+        let expected_balance = tracked_balance - outflow;
+        if balance < expected_balance {
+            TokenAllocations::add_balance_loss(env, token_address, expected_balance - balance);
+        }
+    }
+    TokenAllocations::save_tracked_balance(env, token_address, balance);
+    balance
+}
+
+/// Returns the amount to pay out for withdrawing the allocation amount
+///
+/// If the balance is lower than the total allocation and the amount reserved for
+/// Merkle distributions, every withdrawal is prorated so that the loss is shared
+/// between the shareholders instead of the last withdrawer.
+pub fn get_prorated_amount(env: &Env, token_address: &Address, amount: i128) -> i128 {
+    let balance = get_token_balance(env, token_address, &env.current_contract_address());
+    let total_allocation = TokenAllocations::get_total(env, token_address).unwrap_or(0);
+    let total_claims =
+        total_allocation + MerkleDistributionDataKey::get_reserved(env, token_address);
+    if balance >= total_claims {
+        return amount;
+    }
+    amount
+        .fixed_mul_floor(balance.max(0), total_claims)
+        .unwrap_or(0)
+}
+
+/// Checks that the amount of the token can be distributed
+///
+/// The token has to be whitelisted, and the amount has to be covered by the unused
+/// balance and reach the minimum distribution amount of the token.
+pub fn check_distribution(env: &Env, token_address: &Address, amount: i128) -> Result<(), Error> {
+    let whitelisted_token = match WhitelistedTokens::get_token(env, token_address) {
+        Some(whitelisted_token) => whitelisted_token,
+        None => return Err(Error::TokenNotWhitelisted),
+    };
+
+    // Get the available token balance
+    let balance = get_token_balance(env, token_address, &env.current_contract_address());
+
+    // Check for amount errors
+    if amount <= 0 || balance <= 0 {
+        return Err(Error::ZeroTransferAmount);
+    }
+    // Tokens that are already allocated cannot be distributed again
+    if amount > get_unused_balance(env, token_address) {
+        return Err(Error::InsufficientBalance);
+    }
+    if amount < whitelisted_token.min_distribution {
+        return Err(Error::DistributionAmountBelowMinimum);
+    }
+
+    Ok(())
+}
+
+/// Allocates the amount of tokens to the shareholders based on their shares
+///
+/// If the contract has a protocol fee, the fee is allocated to the fee recipient
+/// and only the remaining amount is split between the shareholders.
+///
+/// Shareholders that opted out of the token are skipped and the shares of the
+/// remaining shareholders are re-normalized to split the whole amount.
+///
+/// Every distribution is recorded as a round. Returns the ID of the round.
+pub fn distribute_tokens(env: &Env, token_address: &Address, amount: i128) -> Result<u32, Error> {
+    distribute_tokens_to(
+        env,
+        token_address,
+        amount,
+        &RecipientKeys::get_shareholders(env),
+    )
+}
+
+/// Allocates the amount of tokens to the given shareholders based on their shares
+///
+/// The shares of the given shareholders are re-normalized to split the whole amount,
+/// the same way as the shares of the opted in shareholders.
+pub fn distribute_tokens_to(
+    env: &Env,
+    token_address: &Address,
+    amount: i128,
+    shareholders: &Vec<Address>,
+) -> Result<u32, Error> {
+    // Collect the shares of the opted in shareholders
+    let mut shares: Vec<ShareDataKey> = Vec::new(env);
+    let mut total_shares = 0;
+    for shareholder in shareholders.iter() {
+        if RecipientKeys::is_opted_out(env, &shareholder, token_address) {
+            continue;
+        }
+        if let Some(share) = RecipientKeys::get_share(env, &shareholder) {
+            total_shares += share.share;
+            shares.push_back(share);
+        }
+    }
+    if total_shares == 0 {
+        return Err(Error::NoOptedInShareholders);
+    }
+
+    let round_id = DistributionRoundDataKey::create(env, token_address, amount, &shares);
+
+    let mut amount = amount;
+
+    sync_tracked_balance(env, token_address, 0);
+
+    // Take the protocol fee before applying the shares
+    let fee_amount = get_protocol_fee_amount(env, amount);
+    allocate_protocol_fee(env, token_address, fee_amount);
+    amount -= fee_amount;
+
+    // For each shareholder, calculate the amount of tokens to distribute
+    for ShareDataKey { shareholder, share } in shares.iter() {
+        // Calculate the amount of tokens to distribute
+        let shareholder_allocation = amount.fixed_mul_floor(share, total_shares).unwrap_or(0);
+
+        if shareholder_allocation > 0 {
+            increase_allocation(env, &shareholder, token_address, shareholder_allocation);
+        }
+    }
+
+    Ok(round_id)
+}
+
+/// Returns the protocol fee taken from the amount, 0 if the contract has no protocol fee
+pub fn get_protocol_fee_amount(env: &Env, amount: i128) -> i128 {
+    match ConfigDataKey::get_protocol_fee(env) {
+        Some(protocol_fee) => amount.fixed_mul_floor(protocol_fee.fee, 10000).unwrap_or(0),
+        None => 0,
+    }
+}
+
+/// Allocates the protocol fee amount to the fee recipient
+pub fn allocate_protocol_fee(env: &Env, token_address: &Address, fee_amount: i128) {
+    if fee_amount <= 0 {
+        return;
+    }
+    if let Some(protocol_fee) = ConfigDataKey::get_protocol_fee(env) {
+        increase_allocation(env, &protocol_fee.recipient, token_address, fee_amount);
+    }
+}
+
+/// Adds the amount to the current allocation of the address
+fn increase_allocation(env: &Env, address: &Address, token_address: &Address, amount: i128) {
+    // Get the current allocation for the address - default to 0
+    let allocation = TokenAllocations::get(env, address, token_address).unwrap_or(0);
+
+    // Update the allocation with the new amount
+    TokenAllocations::save(env, address, token_address, allocation + amount);
+
+    LifetimeTotalsDataKey::add_distributed(env, address, token_address, amount);
+}
+
+/// Returns the Merkle leaf of a claim
+///
+/// The leaf is the SHA-256 hash of the XDR encoded `(index, recipient, amount)` tuple.
+pub fn get_merkle_leaf(env: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(index, recipient.clone(), amount).to_xdr(env))
+}
+
+/// Checks if the proof connects the leaf to the Merkle root
+///
+/// Sibling pairs are sorted before hashing, so the proof doesn't need to encode
+/// the position of the nodes.
+pub fn verify_merkle_proof(
+    env: &Env,
+    root: &BytesN<32>,
+    leaf: BytesN<32>,
+    proof: &Vec<BytesN<32>>,
+) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        let mut data = Bytes::new(env);
+        if node.to_array() <= sibling.to_array() {
+            data.append(&node.into());
+            data.append(&sibling.into());
+        } else {
+            data.append(&sibling.into());
+            data.append(&node.into());
+        }
+        node = env.crypto().sha256(&data);
+    }
+    node == *root
+}
@@ -0,0 +1,10 @@
+use soroban_sdk::Env;
+
+use crate::{errors::Error, storage::config::ConfigDataKey};
+
+pub fn query(env: Env) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ConfigDataKey::get_auto_distribute(&env))
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, rounds::DistributionRoundDataKey},
+};
+
+pub fn query(env: Env, round_id: u32) -> Result<DistributionRoundDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    match DistributionRoundDataKey::get(&env, round_id) {
+        Some(round) => Ok(round),
+        None => Err(Error::RoundNotFound),
+    }
+}
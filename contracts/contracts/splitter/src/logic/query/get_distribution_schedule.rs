@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, schedules::ScheduleDataKey},
+};
+
+pub fn query(env: Env, token_address: Address) -> Result<Option<ScheduleDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(ScheduleDataKey::get(&env, &token_address))
+}
@@ -0,0 +1,21 @@
+use soroban_sdk::{Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, history::LifetimeTotalsDataKey},
+};
+
+pub fn query(
+    env: Env,
+    shareholder: Address,
+    token_address: Address,
+) -> Result<LifetimeTotalsDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+    Ok(LifetimeTotalsDataKey::get_shareholder(
+        &env,
+        &shareholder,
+        &token_address,
+    ))
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, merkle::MerkleDistributionDataKey},
+};
+
+pub fn query(env: Env, distribution_id: u32) -> Result<MerkleDistributionDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    match MerkleDistributionDataKey::get(&env, distribution_id) {
+        Some(distribution) => Ok(distribution),
+        None => Err(Error::MerkleDistributionNotFound),
+    }
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{Address, Env};
+
+use sorosplits_utils::token::get_token_balance;
+
+use crate::{
+    errors::Error,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenAllocations, TokenBalanceDataKey},
+        merkle::MerkleDistributionDataKey,
+    },
+};
+
+pub fn query(env: Env, token_address: Address) -> Result<TokenBalanceDataKey, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let balance = get_token_balance(&env, &token_address, &env.current_contract_address());
+    let total_allocation = TokenAllocations::get_total(&env, &token_address).unwrap_or(0);
+    let reserved = MerkleDistributionDataKey::get_reserved(&env, &token_address);
+
+    Ok(TokenBalanceDataKey {
+        tracked_balance: TokenAllocations::get_tracked_balance(&env, &token_address).unwrap_or(0),
+        balance_loss: TokenAllocations::get_balance_loss(&env, &token_address),
+        balance,
+        total_allocation,
+        shortfall: (total_allocation + reserved - balance).max(0),
+    })
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{BytesN, Env};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, vouchers::VoucherDataKey},
+};
+
+pub fn query(env: Env) -> Result<Option<BytesN<32>>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    Ok(VoucherDataKey::get_signer(&env))
+}
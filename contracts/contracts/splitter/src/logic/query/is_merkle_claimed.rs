@@ -0,0 +1,22 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, merkle::MerkleDistributionDataKey},
+};
+
+pub fn query(env: Env, distribution_id: u32, index: u32) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    if MerkleDistributionDataKey::get(&env, distribution_id).is_none() {
+        return Err(Error::MerkleDistributionNotFound);
+    }
+
+    Ok(MerkleDistributionDataKey::is_claimed(
+        &env,
+        distribution_id,
+        index,
+    ))
+}
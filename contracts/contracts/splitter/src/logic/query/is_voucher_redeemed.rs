@@ -0,0 +1,14 @@
+use soroban_sdk::Env;
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, vouchers::VoucherDataKey},
+};
+
+pub fn query(env: Env, nonce: u64) -> Result<bool, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    Ok(VoucherDataKey::is_redeemed(&env, nonce))
+}
@@ -0,0 +1,35 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, rounds::DistributionRoundDataKey},
+};
+
+/// Maximum number of rounds that can be listed at once
+const MAX_LIST_LIMIT: u32 = 50;
+
+pub fn query(
+    env: Env,
+    token_address: Address,
+    from: u32,
+    limit: u32,
+) -> Result<Vec<DistributionRoundDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Rounds of the token are listed by their index starting from `from`
+    let count = DistributionRoundDataKey::get_token_count(&env, &token_address);
+    let end = from.saturating_add(limit.min(MAX_LIST_LIMIT)).min(count);
+
+    let mut rounds: Vec<DistributionRoundDataKey> = Vec::new(&env);
+    for index in from..end {
+        if let Some(round) =
+            DistributionRoundDataKey::get_token_round_id(&env, &token_address, index)
+                .and_then(|round_id| DistributionRoundDataKey::get(&env, round_id))
+        {
+            rounds.push_back(round);
+        }
+    }
+    Ok(rounds)
+}
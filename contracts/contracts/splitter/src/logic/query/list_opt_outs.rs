@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, recipients::RecipientKeys},
+};
+
+pub fn query(env: Env, shareholder: Address) -> Result<Vec<Address>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    Ok(RecipientKeys::get_opt_outs(&env, &shareholder))
+}
@@ -0,0 +1,20 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{config::ConfigDataKey, distributions::TokenAllocations},
+};
+
+pub fn query(env: Env) -> Result<Vec<(Address, i128)>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    // Whitelisted or not, every token with allocations is still owed to the shareholders
+    let mut tokens: Vec<(Address, i128)> = Vec::new(&env);
+    for token in TokenAllocations::get_tokens(&env).iter() {
+        let total_allocation = TokenAllocations::get_total(&env, &token).unwrap_or(0);
+        tokens.push_back((token, total_allocation));
+    }
+    Ok(tokens)
+}
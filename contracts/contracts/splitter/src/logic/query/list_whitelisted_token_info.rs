@@ -0,0 +1,24 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::{
+    errors::Error,
+    storage::{
+        config::ConfigDataKey,
+        distributions::{TokenInfoDataKey, WhitelistedTokens},
+    },
+};
+
+pub fn query(env: Env) -> Result<Vec<TokenInfoDataKey>, Error> {
+    if !ConfigDataKey::exists(&env) {
+        return Err(Error::NotInitialized);
+    };
+
+    let mut token_infos: Vec<TokenInfoDataKey> = Vec::new(&env);
+    for whitelisted_token in WhitelistedTokens::get_list(&env).iter() {
+        if let Some(token_info) = WhitelistedTokens::get_token_info(&env, &whitelisted_token.token)
+        {
+            token_infos.push_back(token_info);
+        }
+    }
+    Ok(token_infos)
+}
@@ -1,11 +1,14 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Env, Vec};
 
 use crate::{
     errors::Error,
-    storage::{config::ConfigDataKey, distributions::WhitelistedTokens},
+    storage::{
+        config::ConfigDataKey,
+        distributions::{WhitelistedTokenDataKey, WhitelistedTokens},
+    },
 };
 
-pub fn query(env: Env) -> Result<Vec<Address>, Error> {
+pub fn query(env: Env) -> Result<Vec<WhitelistedTokenDataKey>, Error> {
     if !ConfigDataKey::exists(&env) {
         return Err(Error::NotInitialized);
     };
@@ -1,13 +1,41 @@
 mod get_allocation;
+mod get_auto_distribute;
 mod get_config;
+mod get_distribution;
+mod get_distribution_schedule;
+mod get_lifetime_totals;
+mod get_merkle_distribution;
 mod get_share;
+mod get_token_balance;
+mod get_token_lifetime_totals;
 mod get_unused_tokens;
+mod get_voucher_signer;
+mod is_merkle_claimed;
+mod is_voucher_redeemed;
+mod list_distributions;
+mod list_opt_outs;
 mod list_shares;
+mod list_tokens_with_allocations;
+mod list_whitelisted_token_info;
 mod list_whitelisted_tokens;
 
 pub use get_allocation::query as get_allocation;
+pub use get_auto_distribute::query as get_auto_distribute;
 pub use get_config::query as get_config;
+pub use get_distribution::query as get_distribution;
+pub use get_distribution_schedule::query as get_distribution_schedule;
+pub use get_lifetime_totals::query as get_lifetime_totals;
+pub use get_merkle_distribution::query as get_merkle_distribution;
 pub use get_share::query as get_share;
+pub use get_token_balance::query as get_token_balance;
+pub use get_token_lifetime_totals::query as get_token_lifetime_totals;
 pub use get_unused_tokens::query as get_unused_tokens;
+pub use get_voucher_signer::query as get_voucher_signer;
+pub use is_merkle_claimed::query as is_merkle_claimed;
+pub use is_voucher_redeemed::query as is_voucher_redeemed;
+pub use list_distributions::query as list_distributions;
+pub use list_opt_outs::query as list_opt_outs;
 pub use list_shares::query as list_shares;
+pub use list_tokens_with_allocations::query as list_tokens_with_allocations;
+pub use list_whitelisted_token_info::query as list_whitelisted_token_info;
 pub use list_whitelisted_tokens::query as list_whitelisted_tokens;
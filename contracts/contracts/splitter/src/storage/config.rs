@@ -2,12 +2,21 @@ use soroban_sdk::{contracttype, Address, Bytes, Env};
 
 use crate::errors::Error;
 
-use sorosplits_utils::storage::bump_instance;
+use sorosplits_utils::{storage::bump_instance, types::ProtocolFeeDataKey};
 
 #[derive(Clone)]
 #[contracttype]
 pub enum ConfigKeys {
     Config,
+    AutoDistribute,
+}
+
+/// Protocol fee of the contract, a contract without a fee is kept apart from a fee of 0
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub enum ProtocolFeeConfig {
+    Disabled,
+    Enabled(ProtocolFeeDataKey),
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -16,16 +25,29 @@ pub struct ConfigDataKey {
     pub admin: Address,
     pub name: Bytes,
     pub updatable: bool,
+    /// Protocol fee taken from each distribution
+    pub protocol_fee: ProtocolFeeConfig,
 }
 impl ConfigDataKey {
-    /// Initializes the config with the given admin address and updatable flag
-    pub fn init(e: &Env, admin: Address, name: Bytes, updatable: bool) {
+    /// Initializes the config with the given admin address, updatable flag and protocol fee
+    pub fn init(
+        e: &Env,
+        admin: Address,
+        name: Bytes,
+        updatable: bool,
+        protocol_fee: Option<ProtocolFeeDataKey>,
+    ) {
         bump_instance(e);
         let key = ConfigKeys::Config;
+        let protocol_fee = match protocol_fee {
+            Some(protocol_fee) => ProtocolFeeConfig::Enabled(protocol_fee),
+            None => ProtocolFeeConfig::Disabled,
+        };
         let config = ConfigDataKey {
             admin,
             name,
             updatable,
+            protocol_fee,
         };
         e.storage().instance().set(&key, &config);
     }
@@ -53,6 +75,29 @@ impl ConfigDataKey {
         e.storage().instance().set(&key, &self);
     }
 
+    /// Returns the protocol fee taken from each distribution
+    pub fn get_protocol_fee(e: &Env) -> Option<ProtocolFeeDataKey> {
+        match ConfigDataKey::get(e) {
+            Ok(ConfigDataKey {
+                protocol_fee: ProtocolFeeConfig::Enabled(protocol_fee),
+                ..
+            }) => Some(protocol_fee),
+            _ => None,
+        }
+    }
+
+    /// Saves whether the unused balances are distributed before the shares are updated
+    pub fn save_auto_distribute(e: &Env, auto_distribute: bool) {
+        let key = ConfigKeys::AutoDistribute;
+        e.storage().instance().set(&key, &auto_distribute);
+    }
+
+    /// Returns true if the unused balances are distributed before the shares are updated
+    pub fn get_auto_distribute(e: &Env) -> bool {
+        let key = ConfigKeys::AutoDistribute;
+        e.storage().instance().get(&key).unwrap_or(false)
+    }
+
     /// Returns true if ConfigDataKey exists in the storage
     pub fn exists(e: &Env) -> bool {
         bump_instance(e);
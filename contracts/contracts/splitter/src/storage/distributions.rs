@@ -1,13 +1,38 @@
-use soroban_sdk::{contracttype, Address, Env, Vec};
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
 
 use sorosplits_utils::storage::bump_persistent;
 
+/// Maximum number of whitelisted tokens to keep the list cheap to read
+pub const MAX_WHITELISTED_TOKENS: u32 = 20;
+
 #[derive(Clone)]
 #[contracttype]
 pub enum WhitelistKeys {
-    /// Data key for keeping the list of whitelisted tokens.
+    /// Data key for keeping the list of whitelisted tokens with their minimum distribution amounts.
     /// Only whitelisted tokens can be used to distribute the allocation.
     WhitelistedTokens,
+    /// Key for the metadata of a whitelisted token cached at whitelist time.
+    /// token_addr -> TokenInfoDataKey
+    TokenInfo(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct WhitelistedTokenDataKey {
+    pub token: Address,
+    /// Minimum amount of tokens that can be distributed at once
+    pub min_distribution: i128,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TokenInfoDataKey {
+    pub token: Address,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+    /// True if the token is a Stellar Asset Contract
+    pub is_stellar_asset: bool,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -16,26 +41,82 @@ impl WhitelistedTokens {
     // ========== Whitelisted Tokens ==========
 
     /// Update the list of whitelisted tokens
+    /// Minimum distribution amounts are kept for the tokens that stay in the list
     pub fn update(e: &Env, tokens: Vec<Address>) {
-        let key = WhitelistKeys::WhitelistedTokens;
-        e.storage().persistent().set(&key, &tokens);
-        bump_persistent(e, &key);
+        let mut whitelisted_tokens: Vec<WhitelistedTokenDataKey> = Vec::new(e);
+        for token in tokens.iter() {
+            let min_distribution = match Self::get_token(e, &token) {
+                Some(whitelisted_token) => whitelisted_token.min_distribution,
+                None => 0,
+            };
+            whitelisted_tokens.push_back(WhitelistedTokenDataKey {
+                token,
+                min_distribution,
+            });
+        }
+        Self::save_list(e, &whitelisted_tokens);
+    }
+
+    /// Adds the token to the end of the whitelisted tokens list
+    pub fn add(e: &Env, token: &Address) {
+        let mut whitelisted_tokens = Self::get_list(e);
+        whitelisted_tokens.push_back(WhitelistedTokenDataKey {
+            token: token.clone(),
+            min_distribution: 0,
+        });
+        Self::save_list(e, &whitelisted_tokens);
+    }
+
+    /// Removes the token from the whitelisted tokens list
+    pub fn remove(e: &Env, token: &Address) {
+        let mut whitelisted_tokens = Self::get_list(e);
+        if let Some(index) = whitelisted_tokens
+            .iter()
+            .position(|whitelisted_token| whitelisted_token.token == *token)
+        {
+            whitelisted_tokens.remove(index as u32);
+            Self::save_list(e, &whitelisted_tokens);
+        }
+    }
+
+    /// Updates the minimum distribution amount for a whitelisted token
+    pub fn update_min_distribution(e: &Env, token: &Address, min_distribution: i128) {
+        let mut whitelisted_tokens = Self::get_list(e);
+        if let Some(index) = whitelisted_tokens
+            .iter()
+            .position(|whitelisted_token| whitelisted_token.token == *token)
+        {
+            whitelisted_tokens.set(
+                index as u32,
+                WhitelistedTokenDataKey {
+                    token: token.clone(),
+                    min_distribution,
+                },
+            );
+            Self::save_list(e, &whitelisted_tokens);
+        }
     }
 
     /// Checks if the token is whitelisted
     pub fn check_token_address(e: &Env, token: &Address) -> bool {
-        let tokens: Vec<Address> = Self::get_list(e);
-        tokens.contains(token)
+        Self::get_token(e, token).is_some()
+    }
+
+    /// Gets the whitelisted token data for the token
+    pub fn get_token(e: &Env, token: &Address) -> Option<WhitelistedTokenDataKey> {
+        Self::get_list(e)
+            .iter()
+            .find(|whitelisted_token| whitelisted_token.token == *token)
     }
 
     /// Gets the list of whitelisted tokens
     /// Returns an empty vector if the list doesn't exist
-    pub fn get_list(e: &Env) -> Vec<Address> {
+    pub fn get_list(e: &Env) -> Vec<WhitelistedTokenDataKey> {
         let key = WhitelistKeys::WhitelistedTokens;
         let res = e
             .storage()
             .persistent()
-            .get::<WhitelistKeys, Vec<Address>>(&key);
+            .get::<WhitelistKeys, Vec<WhitelistedTokenDataKey>>(&key);
         match res {
             Some(tokens) => {
                 bump_persistent(e, &key);
@@ -44,6 +125,43 @@ impl WhitelistedTokens {
             None => Vec::new(&e),
         }
     }
+
+    fn save_list(e: &Env, tokens: &Vec<WhitelistedTokenDataKey>) {
+        let key = WhitelistKeys::WhitelistedTokens;
+        e.storage().persistent().set(&key, tokens);
+        bump_persistent(e, &key);
+    }
+
+    // ========== Token Info ==========
+
+    /// Saves the cached metadata for the token
+    pub fn save_token_info(e: &Env, token_info: &TokenInfoDataKey) {
+        let key = WhitelistKeys::TokenInfo(token_info.token.clone());
+        e.storage().persistent().set(&key, token_info);
+        bump_persistent(e, &key);
+    }
+
+    /// Gets the cached metadata for the token
+    pub fn get_token_info(e: &Env, token: &Address) -> Option<TokenInfoDataKey> {
+        let key = WhitelistKeys::TokenInfo(token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<WhitelistKeys, TokenInfoDataKey>(&key);
+        match res {
+            Some(token_info) => {
+                bump_persistent(e, &key);
+                Some(token_info)
+            }
+            None => None,
+        }
+    }
+
+    /// Removes the cached metadata for the token
+    pub fn remove_token_info(e: &Env, token: &Address) {
+        let key = WhitelistKeys::TokenInfo(token.clone());
+        e.storage().persistent().remove(&key);
+    }
 }
 
 #[derive(Clone)]
@@ -55,6 +173,33 @@ pub enum DistributionKeys {
     /// Key for mapping the allocation amount for a shareholder.
     /// (user_addr, token_addr) -> allocation_amount
     Allocation(Address, Address),
+    /// Key for the list of tokens that have a total allocation.
+    /// Tokens stay in the list until all allocations are withdrawn.
+    TokensWithAllocations,
+    /// Key for the token balance recorded after the last operation of the contract.
+    /// token_addr -> balance
+    TrackedBalance(Address),
+    /// Key for the balance that left the contract without going through it.
+    /// token_addr -> amount
+    BalanceLoss(Address),
+    /// Key for the list of addresses that have an allocation for a token.
+    /// token_addr -> Vec<user_addr>
+    AllocationHolders(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct TokenBalanceDataKey {
+    /// Balance recorded after the last operation of the contract
+    pub tracked_balance: i128,
+    /// Balance that left the contract without going through it, e.g. by rebasing or clawback
+    pub balance_loss: i128,
+    /// Actual balance of the contract
+    pub balance: i128,
+    /// Sum of all the allocations
+    pub total_allocation: i128,
+    /// Amount of allocations and Merkle reservations that are not backed by the balance
+    pub shortfall: i128,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -62,11 +207,13 @@ pub struct TokenAllocations {}
 impl TokenAllocations {
     // ========== User Allocation ==========
 
-    /// Initializes the share for the shareholder
+    /// Saves the allocation for the shareholder and keeps the total allocation in sync
     pub fn save(e: &Env, shareholder: &Address, token: &Address, allocation: i128) {
+        // Only the difference from the previous allocation affects the total
+        let previous_allocation = Self::get(e, shareholder, token).unwrap_or(0);
         match Self::get_total(e, token) {
             Some(total_allocation) => {
-                let new_total_allocation = total_allocation + allocation;
+                let new_total_allocation = total_allocation + allocation - previous_allocation;
                 Self::save_total(e, token, new_total_allocation);
             }
             None => {
@@ -75,6 +222,11 @@ impl TokenAllocations {
         }
 
         let key = DistributionKeys::Allocation(shareholder.clone(), token.clone());
+        if !e.storage().persistent().has(&key) {
+            let mut holders = Self::get_holders(e, token);
+            holders.push_back(shareholder.clone());
+            Self::save_holders(e, token, &holders);
+        }
         e.storage().persistent().set(&key, &allocation);
         bump_persistent(e, &key);
     }
@@ -96,6 +248,39 @@ impl TokenAllocations {
 
         let key = DistributionKeys::Allocation(shareholder.clone(), token.clone());
         e.storage().persistent().remove(&key);
+
+        let mut holders = Self::get_holders(e, token);
+        if let Some(index) = holders.first_index_of(shareholder) {
+            holders.remove(index);
+            Self::save_holders(e, token, &holders);
+        }
+    }
+
+    /// Gets the list of addresses that have an allocation for the token
+    /// Returns an empty vector if the list doesn't exist
+    pub fn get_holders(e: &Env, token: &Address) -> Vec<Address> {
+        let key = DistributionKeys::AllocationHolders(token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DistributionKeys, Vec<Address>>(&key);
+        match res {
+            Some(holders) => {
+                bump_persistent(e, &key);
+                holders
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    fn save_holders(e: &Env, token: &Address, holders: &Vec<Address>) {
+        let key = DistributionKeys::AllocationHolders(token.clone());
+        if holders.is_empty() {
+            e.storage().persistent().remove(&key);
+            return;
+        }
+        e.storage().persistent().set(&key, holders);
+        bump_persistent(e, &key);
     }
 
     pub fn get(e: &Env, shareholder: &Address, token: &Address) -> Option<i128> {
@@ -116,11 +301,23 @@ impl TokenAllocations {
         let key = DistributionKeys::TotalAllocation(token.clone());
         e.storage().persistent().set(&key, &total_allocation);
         bump_persistent(e, &key);
+
+        let mut tokens = Self::get_tokens(e);
+        if !tokens.contains(token) {
+            tokens.push_back(token.clone());
+            Self::save_tokens(e, &tokens);
+        }
     }
 
     pub fn remove_total(e: &Env, token: &Address) {
         let key = DistributionKeys::TotalAllocation(token.clone());
         e.storage().persistent().remove(&key);
+
+        let mut tokens = Self::get_tokens(e);
+        if let Some(index) = tokens.first_index_of(token) {
+            tokens.remove(index);
+            Self::save_tokens(e, &tokens);
+        }
     }
 
     pub fn get_total(e: &Env, token: &Address) -> Option<i128> {
@@ -134,4 +331,68 @@ impl TokenAllocations {
             None => None,
         }
     }
+
+    // ========== Tokens With Allocations ==========
+
+    /// Gets the list of tokens that have a total allocation
+    /// Returns an empty vector if the list doesn't exist
+    pub fn get_tokens(e: &Env) -> Vec<Address> {
+        let key = DistributionKeys::TokensWithAllocations;
+        let res = e
+            .storage()
+            .persistent()
+            .get::<DistributionKeys, Vec<Address>>(&key);
+        match res {
+            Some(tokens) => {
+                bump_persistent(e, &key);
+                tokens
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    fn save_tokens(e: &Env, tokens: &Vec<Address>) {
+        let key = DistributionKeys::TokensWithAllocations;
+        e.storage().persistent().set(&key, tokens);
+        bump_persistent(e, &key);
+    }
+
+    // ========== Tracked Balance ==========
+
+    pub fn save_tracked_balance(e: &Env, token: &Address, balance: i128) {
+        let key = DistributionKeys::TrackedBalance(token.clone());
+        e.storage().persistent().set(&key, &balance);
+        bump_persistent(e, &key);
+    }
+
+    pub fn get_tracked_balance(e: &Env, token: &Address) -> Option<i128> {
+        let key = DistributionKeys::TrackedBalance(token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(balance) => {
+                bump_persistent(e, &key);
+                Some(balance)
+            }
+            None => None,
+        }
+    }
+
+    pub fn add_balance_loss(e: &Env, token: &Address, amount: i128) {
+        let key = DistributionKeys::BalanceLoss(token.clone());
+        let balance_loss: i128 = e.storage().persistent().get(&key).unwrap_or(0);
+        e.storage().persistent().set(&key, &(balance_loss + amount));
+        bump_persistent(e, &key);
+    }
+
+    pub fn get_balance_loss(e: &Env, token: &Address) -> i128 {
+        let key = DistributionKeys::BalanceLoss(token.clone());
+        let res = e.storage().persistent().get(&key);
+        match res {
+            Some(balance_loss) => {
+                bump_persistent(e, &key);
+                balance_loss
+            }
+            None => 0,
+        }
+    }
 }
@@ -0,0 +1,77 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use sorosplits_utils::storage::bump_persistent;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum HistoryKeys {
+    /// Key for the lifetime totals of a shareholder for a token.
+    /// (user_addr, token_addr) -> LifetimeTotalsDataKey
+    Shareholder(Address, Address),
+    /// Key for the lifetime totals of a token.
+    /// token_addr -> LifetimeTotalsDataKey
+    Token(Address),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+#[contracttype]
+pub struct LifetimeTotalsDataKey {
+    /// Total amount of tokens allocated by distributions
+    pub distributed: i128,
+    /// Total amount of tokens transferred by withdrawals
+    pub withdrawn: i128,
+    /// Total amount of tokens removed from allocations by reconciliations
+    pub written_off: i128,
+}
+impl LifetimeTotalsDataKey {
+    /// Returns the lifetime totals of the shareholder for the token - defaults to 0
+    pub fn get_shareholder(e: &Env, shareholder: &Address, token: &Address) -> Self {
+        Self::get(
+            e,
+            &HistoryKeys::Shareholder(shareholder.clone(), token.clone()),
+        )
+    }
+
+    /// Returns the lifetime totals of the token - defaults to 0
+    pub fn get_token(e: &Env, token: &Address) -> Self {
+        Self::get(e, &HistoryKeys::Token(token.clone()))
+    }
+
+    /// Adds the amount to the distributed totals of the shareholder and the token
+    pub fn add_distributed(e: &Env, shareholder: &Address, token: &Address, amount: i128) {
+        Self::update(e, shareholder, token, |totals| totals.distributed += amount);
+    }
+
+    /// Adds the amount to the withdrawn totals of the shareholder and the token
+    pub fn add_withdrawn(e: &Env, shareholder: &Address, token: &Address, amount: i128) {
+        Self::update(e, shareholder, token, |totals| totals.withdrawn += amount);
+    }
+
+    /// Adds the amount to the written off totals of the shareholder and the token
+    pub fn add_written_off(e: &Env, shareholder: &Address, token: &Address, amount: i128) {
+        Self::update(e, shareholder, token, |totals| totals.written_off += amount);
+    }
+
+    fn update(e: &Env, shareholder: &Address, token: &Address, f: impl Fn(&mut Self)) {
+        for key in [
+            HistoryKeys::Shareholder(shareholder.clone(), token.clone()),
+            HistoryKeys::Token(token.clone()),
+        ] {
+            let mut totals = Self::get(e, &key);
+            f(&mut totals);
+            e.storage().persistent().set(&key, &totals);
+            bump_persistent(e, &key);
+        }
+    }
+
+    fn get(e: &Env, key: &HistoryKeys) -> Self {
+        let res = e.storage().persistent().get::<HistoryKeys, Self>(key);
+        match res {
+            Some(totals) => {
+                bump_persistent(e, key);
+                totals
+            }
+            None => Self::default(),
+        }
+    }
+}
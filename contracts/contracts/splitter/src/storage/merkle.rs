@@ -0,0 +1,198 @@
+use soroban_fixed_point_math::FixedPoint;
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use sorosplits_utils::storage::{bump_instance, bump_persistent};
+
+/// Number of claim flags stored in a single bitmap word
+const BITMAP_WORD_SIZE: u32 = 128;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum MerkleKeys {
+    /// Key for the number of Merkle distributions.
+    /// The count is also the ID of the last distribution.
+    DistributionCount,
+    /// Key for a Merkle distribution.
+    /// distribution_id -> MerkleDistributionDataKey
+    Distribution(u32),
+    /// Key for a word of the claimed bitmap of a Merkle distribution.
+    /// (distribution_id, word_index) -> u128
+    ClaimedBitmap(u32, u32),
+    /// Key for the amount of tokens reserved for the unclaimed Merkle allocations.
+    /// token_addr -> reserved_amount
+    ReservedAmount(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct MerkleDistributionDataKey {
+    /// Incrementing ID of the distribution starting from 1
+    pub id: u32,
+    pub token: Address,
+    /// Root of the Merkle tree of (index, recipient, amount) leaves
+    pub root: BytesN<32>,
+    /// Total amount of tokens that can be claimed
+    pub amount: i128,
+    /// Amount of tokens that have been claimed
+    pub claimed: i128,
+    /// Amount of tokens reserved for the unclaimed allocations, lower than the
+    /// unclaimed amount after the allocations are reconciled with a shortfall
+    pub reserved: i128,
+    /// Last ledger sequence the allocations can be claimed on
+    pub expiry: u32,
+    /// Whether the unclaimed amount has been released back to the unused balance
+    pub closed: bool,
+}
+impl MerkleDistributionDataKey {
+    /// Saves a new distribution for the token and returns its ID
+    pub fn create(e: &Env, token: &Address, root: &BytesN<32>, amount: i128, expiry: u32) -> u32 {
+        let id = Self::get_count(e) + 1;
+        let distribution = MerkleDistributionDataKey {
+            id,
+            token: token.clone(),
+            root: root.clone(),
+            amount,
+            claimed: 0,
+            reserved: amount,
+            expiry,
+            closed: false,
+        };
+        distribution.save(e);
+
+        bump_instance(e);
+        e.storage()
+            .instance()
+            .set(&MerkleKeys::DistributionCount, &id);
+
+        Self::save_reserved(e, token, Self::get_reserved(e, token) + amount);
+
+        id
+    }
+
+    /// Returns the part of the reserved amount that backs the claim amount
+    pub fn get_claim_reserve(&self, amount: i128) -> i128 {
+        let unclaimed = self.amount - self.claimed;
+        if self.reserved >= unclaimed {
+            return amount;
+        }
+        amount
+            .fixed_mul_floor(self.reserved, unclaimed)
+            .unwrap_or(0)
+    }
+
+    /// Records the claimed amount and releases its part of the reserved amount
+    pub fn claim(&mut self, e: &Env, index: u32, amount: i128) {
+        let released = self.get_claim_reserve(amount);
+        self.claimed += amount;
+        self.reserved -= released;
+        self.save(e);
+
+        let key = MerkleKeys::ClaimedBitmap(self.id, index / BITMAP_WORD_SIZE);
+        let word: u128 = e.storage().persistent().get(&key).unwrap_or(0);
+        e.storage()
+            .persistent()
+            .set(&key, &(word | (1 << (index % BITMAP_WORD_SIZE))));
+        bump_persistent(e, &key);
+
+        Self::save_reserved(
+            e,
+            &self.token,
+            Self::get_reserved(e, &self.token) - released,
+        );
+    }
+
+    /// Closes the distribution and releases the remaining reserved amount
+    ///
+    /// Returns the released amount
+    pub fn close(&mut self, e: &Env) -> i128 {
+        let released = self.reserved;
+        self.reserved = 0;
+        self.closed = true;
+        self.save(e);
+
+        Self::save_reserved(
+            e,
+            &self.token,
+            Self::get_reserved(e, &self.token) - released,
+        );
+
+        released
+    }
+
+    /// Lowers the reserved amount of the distribution to cover a balance shortfall
+    pub fn write_down(&mut self, e: &Env, reserved: i128) {
+        let written_off = self.reserved - reserved;
+        self.reserved = reserved;
+        self.save(e);
+
+        Self::save_reserved(
+            e,
+            &self.token,
+            Self::get_reserved(e, &self.token) - written_off,
+        );
+    }
+
+    /// Checks if the leaf at the index has been claimed
+    pub fn is_claimed(e: &Env, id: u32, index: u32) -> bool {
+        let key = MerkleKeys::ClaimedBitmap(id, index / BITMAP_WORD_SIZE);
+        match e.storage().persistent().get::<MerkleKeys, u128>(&key) {
+            Some(word) => {
+                bump_persistent(e, &key);
+                word & (1 << (index % BITMAP_WORD_SIZE)) != 0
+            }
+            None => false,
+        }
+    }
+
+    /// Returns the distribution with the given ID
+    pub fn get(e: &Env, id: u32) -> Option<MerkleDistributionDataKey> {
+        let key = MerkleKeys::Distribution(id);
+        let res = e
+            .storage()
+            .persistent()
+            .get::<MerkleKeys, MerkleDistributionDataKey>(&key);
+        match res {
+            Some(distribution) => {
+                bump_persistent(e, &key);
+                Some(distribution)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the number of distributions
+    pub fn get_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&MerkleKeys::DistributionCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the amount of tokens reserved for the unclaimed allocations - defaults to 0
+    pub fn get_reserved(e: &Env, token: &Address) -> i128 {
+        let key = MerkleKeys::ReservedAmount(token.clone());
+        match e.storage().persistent().get(&key) {
+            Some(reserved) => {
+                bump_persistent(e, &key);
+                reserved
+            }
+            None => 0,
+        }
+    }
+
+    fn save_reserved(e: &Env, token: &Address, reserved: i128) {
+        let key = MerkleKeys::ReservedAmount(token.clone());
+        if reserved == 0 {
+            e.storage().persistent().remove(&key);
+            return;
+        }
+        e.storage().persistent().set(&key, &reserved);
+        bump_persistent(e, &key);
+    }
+
+    fn save(&self, e: &Env) {
+        let key = MerkleKeys::Distribution(self.id);
+        e.storage().persistent().set(&key, self);
+        bump_persistent(e, &key);
+    }
+}
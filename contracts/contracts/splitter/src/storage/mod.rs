@@ -1,3 +1,8 @@
 pub mod config;
 pub mod distributions;
+pub mod history;
+pub mod merkle;
 pub mod recipients;
+pub mod rounds;
+pub mod schedules;
+pub mod vouchers;
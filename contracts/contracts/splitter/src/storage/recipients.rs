@@ -10,6 +10,9 @@ pub enum RecipientKeys {
     /// Data key for keeping the share of a shareholder.
     /// shareholder_addr -> ShareDataKey
     Share(Address),
+    /// Data key for keeping the tokens a shareholder opted out of.
+    /// shareholder_addr -> Vec<token_addr>
+    OptOuts(Address),
 }
 impl RecipientKeys {
     /// Initializes the share for the shareholder
@@ -71,4 +74,42 @@ impl RecipientKeys {
         let key = RecipientKeys::Shareholders;
         e.storage().persistent().remove(&key);
     }
+
+    /// Saves the list of tokens the shareholder opted out of
+    pub fn save_opt_outs(e: &Env, shareholder: &Address, tokens: &Vec<Address>) {
+        let key = RecipientKeys::OptOuts(shareholder.clone());
+        if tokens.is_empty() {
+            e.storage().persistent().remove(&key);
+            return;
+        }
+        e.storage().persistent().set(&key, tokens);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the list of tokens the shareholder opted out of
+    pub fn get_opt_outs(e: &Env, shareholder: &Address) -> Vec<Address> {
+        let key = RecipientKeys::OptOuts(shareholder.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<RecipientKeys, Vec<Address>>(&key);
+        match res {
+            Some(tokens) => {
+                bump_persistent(e, &key);
+                tokens
+            }
+            None => Vec::new(e),
+        }
+    }
+
+    /// Removes the list of tokens the shareholder opted out of
+    pub fn remove_opt_outs(e: &Env, shareholder: &Address) {
+        let key = RecipientKeys::OptOuts(shareholder.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns true if the shareholder opted out of the token
+    pub fn is_opted_out(e: &Env, shareholder: &Address, token: &Address) -> bool {
+        Self::get_opt_outs(e, shareholder).contains(token)
+    }
 }
@@ -0,0 +1,107 @@
+use soroban_sdk::{contracttype, xdr::ToXdr, Address, BytesN, Env, Vec};
+
+use sorosplits_utils::{
+    storage::{bump_instance, bump_persistent},
+    types::ShareDataKey,
+};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum RoundKeys {
+    /// Key for the number of distribution rounds.
+    /// The count is also the ID of the last round.
+    RoundCount,
+    /// Key for a distribution round.
+    /// round_id -> DistributionRoundDataKey
+    Round(u32),
+    /// Key for the number of distribution rounds of a token.
+    /// token_addr -> count
+    TokenRoundCount(Address),
+    /// Key for mapping the index of a token round to the round ID.
+    /// (token_addr, index) -> round_id
+    TokenRound(Address, u32),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct DistributionRoundDataKey {
+    /// Incrementing ID of the round starting from 1
+    pub id: u32,
+    pub token: Address,
+    /// Amount of tokens distributed in the round, including the protocol fee
+    pub amount: i128,
+    /// Ledger sequence of the distribution
+    pub ledger: u32,
+    /// SHA-256 hash of the XDR encoded shares used for the distribution
+    pub shares_hash: BytesN<32>,
+}
+impl DistributionRoundDataKey {
+    /// Saves a new round for the token and returns its ID
+    ///
+    /// The shares are the ones used for the split, without the skipped shareholders
+    pub fn create(e: &Env, token: &Address, amount: i128, shares: &Vec<ShareDataKey>) -> u32 {
+        let id = Self::get_count(e) + 1;
+        let round = DistributionRoundDataKey {
+            id,
+            token: token.clone(),
+            amount,
+            ledger: e.ledger().sequence(),
+            shares_hash: e.crypto().sha256(&shares.clone().to_xdr(e)),
+        };
+
+        let key = RoundKeys::Round(id);
+        e.storage().persistent().set(&key, &round);
+        bump_persistent(e, &key);
+
+        bump_instance(e);
+        e.storage().instance().set(&RoundKeys::RoundCount, &id);
+
+        // Index the round for the token
+        let token_count = Self::get_token_count(e, token);
+        let key = RoundKeys::TokenRound(token.clone(), token_count);
+        e.storage().persistent().set(&key, &id);
+        bump_persistent(e, &key);
+
+        let key = RoundKeys::TokenRoundCount(token.clone());
+        e.storage().persistent().set(&key, &(token_count + 1));
+        bump_persistent(e, &key);
+
+        id
+    }
+
+    /// Returns the round with the given ID
+    pub fn get(e: &Env, id: u32) -> Option<DistributionRoundDataKey> {
+        let key = RoundKeys::Round(id);
+        let res = e
+            .storage()
+            .persistent()
+            .get::<RoundKeys, DistributionRoundDataKey>(&key);
+        match res {
+            Some(round) => {
+                bump_persistent(e, &key);
+                Some(round)
+            }
+            None => None,
+        }
+    }
+
+    /// Returns the ID of the round at the index of the token rounds
+    pub fn get_token_round_id(e: &Env, token: &Address, index: u32) -> Option<u32> {
+        let key = RoundKeys::TokenRound(token.clone(), index);
+        e.storage().persistent().get(&key)
+    }
+
+    /// Returns the number of rounds
+    pub fn get_count(e: &Env) -> u32 {
+        e.storage()
+            .instance()
+            .get(&RoundKeys::RoundCount)
+            .unwrap_or(0)
+    }
+
+    /// Returns the number of rounds of the token
+    pub fn get_token_count(e: &Env, token: &Address) -> u32 {
+        let key = RoundKeys::TokenRoundCount(token.clone());
+        e.storage().persistent().get(&key).unwrap_or(0)
+    }
+}
@@ -0,0 +1,57 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use sorosplits_utils::storage::bump_persistent;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum ScheduleKeys {
+    /// Key for the recurring distribution schedule of a token.
+    /// token_addr -> ScheduleDataKey
+    Schedule(Address),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ScheduleDataKey {
+    /// Minimum number of ledgers between two scheduled distributions
+    pub interval: u32,
+    /// Reward paid to the keeper in basis points of the unused balance
+    pub keeper_reward: i128,
+    /// Ledger sequence of the last scheduled distribution
+    pub last_distribution: u32,
+}
+impl ScheduleDataKey {
+    /// Saves the distribution schedule for the token
+    pub fn save(e: &Env, token: &Address, schedule: &ScheduleDataKey) {
+        let key = ScheduleKeys::Schedule(token.clone());
+        e.storage().persistent().set(&key, schedule);
+        bump_persistent(e, &key);
+    }
+
+    /// Returns the distribution schedule for the token
+    pub fn get(e: &Env, token: &Address) -> Option<ScheduleDataKey> {
+        let key = ScheduleKeys::Schedule(token.clone());
+        let res = e
+            .storage()
+            .persistent()
+            .get::<ScheduleKeys, ScheduleDataKey>(&key);
+        match res {
+            Some(schedule) => {
+                bump_persistent(e, &key);
+                Some(schedule)
+            }
+            None => None,
+        }
+    }
+
+    /// Removes the distribution schedule for the token
+    pub fn remove(e: &Env, token: &Address) {
+        let key = ScheduleKeys::Schedule(token.clone());
+        e.storage().persistent().remove(&key);
+    }
+
+    /// Returns true if the interval has elapsed since the last distribution
+    pub fn is_due(&self, e: &Env) -> bool {
+        e.ledger().sequence() >= self.last_distribution.saturating_add(self.interval)
+    }
+}
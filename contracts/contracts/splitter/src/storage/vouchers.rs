@@ -0,0 +1,63 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env};
+
+use sorosplits_utils::storage::{bump_instance, bump_persistent};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum VoucherKeys {
+    /// Key for the ed25519 public key that signs the claim vouchers.
+    Signer,
+    /// Key for marking the nonce of a redeemed voucher.
+    /// nonce -> bool
+    Redeemed(u64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct VoucherDataKey {
+    /// Address of the splitter contract the voucher can be redeemed on
+    pub contract: Address,
+    pub recipient: Address,
+    pub token: Address,
+    pub amount: i128,
+    /// Unique number of the voucher to prevent replays
+    pub nonce: u64,
+    /// Last ledger sequence the voucher can be redeemed on
+    pub expiry: u32,
+}
+impl VoucherDataKey {
+    /// Saves the public key of the voucher signer
+    pub fn save_signer(e: &Env, public_key: &BytesN<32>) {
+        bump_instance(e);
+        e.storage().instance().set(&VoucherKeys::Signer, public_key);
+    }
+
+    /// Removes the public key of the voucher signer
+    pub fn remove_signer(e: &Env) {
+        bump_instance(e);
+        e.storage().instance().remove(&VoucherKeys::Signer);
+    }
+
+    /// Returns the public key of the voucher signer
+    pub fn get_signer(e: &Env) -> Option<BytesN<32>> {
+        bump_instance(e);
+        e.storage().instance().get(&VoucherKeys::Signer)
+    }
+
+    /// Marks the nonce as redeemed
+    pub fn save_redeemed(e: &Env, nonce: u64) {
+        let key = VoucherKeys::Redeemed(nonce);
+        e.storage().persistent().set(&key, &true);
+        bump_persistent(e, &key);
+    }
+
+    /// Checks if a voucher with the nonce has been redeemed
+    pub fn is_redeemed(e: &Env, nonce: u64) -> bool {
+        let key = VoucherKeys::Redeemed(nonce);
+        if e.storage().persistent().has(&key) {
+            bump_persistent(e, &key);
+            return true;
+        }
+        false
+    }
+}
@@ -1,10 +1,22 @@
+mod add_whitelisted_token;
+mod claim_merkle_allocation;
+mod close_merkle_distribution;
+mod create_merkle_distribution;
+mod distribute_to_shareholders;
 mod distribute_tokens;
 mod helpers;
 mod init;
 mod lock_contract;
+mod poke;
+mod reconcile_allocations;
+mod redeem_voucher;
+mod remove_whitelisted_token;
 mod transfer_tokens;
+mod update_distribution_schedule;
+mod update_min_distribution;
 mod update_name;
 mod update_shares;
+mod update_token_opt_out;
 mod update_whitelisted_tokens;
 mod withdraw_allocation;
 mod withdraw_external_allocation;
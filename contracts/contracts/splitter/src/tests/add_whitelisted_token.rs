@@ -0,0 +1,94 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::distributions::{WhitelistedTokenDataKey, MAX_WHITELISTED_TOKENS},
+    tests::helpers::{create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.add_whitelisted_token(&token_address_1);
+    splitter.update_min_distribution(&token_address_1, &1_000);
+    splitter.add_whitelisted_token(&token_address_2);
+
+    assert_eq!(
+        splitter.list_whitelisted_tokens(),
+        vec![
+            &env,
+            WhitelistedTokenDataKey {
+                token: token_address_1,
+                min_distribution: 1_000,
+            },
+            WhitelistedTokenDataKey {
+                token: token_address_2,
+                min_distribution: 0,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+
+    assert!(splitter.try_add_whitelisted_token(&token_address).is_err());
+}
+
+#[test]
+fn test_invalid_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_add_whitelisted_token(&Address::generate(&env)),
+        Err(Ok(Error::InvalidToken))
+    );
+}
+
+#[test]
+fn test_token_already_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.add_whitelisted_token(&token_address);
+
+    assert_eq!(
+        splitter.try_add_whitelisted_token(&token_address),
+        Err(Ok(Error::TokenAlreadyWhitelisted))
+    );
+}
+
+#[test]
+fn test_whitelist_limit_reached() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    for _ in 0..MAX_WHITELISTED_TOKENS {
+        let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+        splitter.add_whitelisted_token(&token_address);
+    }
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    assert_eq!(
+        splitter.try_add_whitelisted_token(&token_address),
+        Err(Ok(Error::WhitelistLimitReached))
+    );
+}
@@ -0,0 +1,248 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, BytesN, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::history::LifetimeTotalsDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+fn get_leaf(env: &Env, index: u32, recipient: &Address, amount: i128) -> BytesN<32> {
+    env.crypto()
+        .sha256(&(index, recipient.clone(), amount).to_xdr(env))
+}
+
+fn hash_pair(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a.to_array() <= b.to_array() {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    let mut data = Bytes::new(env);
+    data.append(&first.clone().into());
+    data.append(&second.clone().into());
+    env.crypto().sha256(&data)
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipient_1 = Address::generate(&env);
+    let recipient_2 = Address::generate(&env);
+    let recipient_3 = Address::generate(&env);
+    let leaf_1 = get_leaf(&env, 0, &recipient_1, 100);
+    let leaf_2 = get_leaf(&env, 1, &recipient_2, 200);
+    let leaf_3 = get_leaf(&env, 2, &recipient_3, 300);
+    let node = hash_pair(&env, &leaf_1, &leaf_2);
+    let root = hash_pair(&env, &node, &leaf_3);
+
+    let id = splitter.create_merkle_distribution(&token_address, &600, &root, &100);
+
+    splitter.claim_merkle_allocation(
+        &id,
+        &0,
+        &recipient_1,
+        &100,
+        &vec![&env, leaf_2.clone(), leaf_3.clone()],
+    );
+    splitter.claim_merkle_allocation(&id, &2, &recipient_3, &300, &vec![&env, node]);
+
+    assert_eq!(token.balance(&recipient_1), 100);
+    assert_eq!(token.balance(&recipient_3), 300);
+    assert_eq!(splitter.get_merkle_distribution(&id).claimed, 400);
+    assert!(splitter.is_merkle_claimed(&id, &0));
+    assert!(!splitter.is_merkle_claimed(&id, &1));
+    assert!(splitter.is_merkle_claimed(&id, &2));
+    assert_eq!(
+        splitter.get_lifetime_totals(&recipient_1, &token_address),
+        LifetimeTotalsDataKey {
+            distributed: 100,
+            withdrawn: 100,
+            written_off: 0,
+        }
+    );
+
+    // Only the unclaimed amount stays reserved
+    assert_eq!(splitter.get_unused_tokens(&token_address), 400);
+
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(
+            &id,
+            &0,
+            &recipient_1,
+            &100,
+            &vec![&env, leaf_2, leaf_3]
+        ),
+        Err(Ok(Error::MerkleAllocationAlreadyClaimed))
+    );
+}
+
+#[test]
+fn test_invalid_proof() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipient_1 = Address::generate(&env);
+    let recipient_2 = Address::generate(&env);
+    let leaf_1 = get_leaf(&env, 0, &recipient_1, 100);
+    let leaf_2 = get_leaf(&env, 1, &recipient_2, 200);
+    let root = hash_pair(&env, &leaf_1, &leaf_2);
+
+    let id = splitter.create_merkle_distribution(&token_address, &300, &root, &100);
+
+    // Amount doesn't match the leaf
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(
+            &id,
+            &0,
+            &recipient_1,
+            &200,
+            &vec![&env, leaf_2.clone()]
+        ),
+        Err(Ok(Error::InvalidMerkleProof))
+    );
+    // Recipient doesn't match the leaf
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(
+            &id,
+            &0,
+            &recipient_2,
+            &100,
+            &vec![&env, leaf_2.clone()]
+        ),
+        Err(Ok(Error::InvalidMerkleProof))
+    );
+    // Index doesn't match the leaf
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(
+            &id,
+            &1,
+            &recipient_1,
+            &100,
+            &vec![&env, leaf_2.clone()]
+        ),
+        Err(Ok(Error::InvalidMerkleProof))
+    );
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(&2, &0, &recipient_1, &100, &vec![&env, leaf_2]),
+        Err(Ok(Error::MerkleDistributionNotFound))
+    );
+}
+
+#[test]
+fn test_distribution_expired() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipient = Address::generate(&env);
+    let root = get_leaf(&env, 0, &recipient, 100);
+    let id = splitter.create_merkle_distribution(&token_address, &100, &root, &100);
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(&id, &0, &recipient, &100, &vec![&env]),
+        Err(Ok(Error::MerkleDistributionExpired))
+    );
+}
+
+#[test]
+fn test_distribution_exhausted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let recipient_1 = Address::generate(&env);
+    let recipient_2 = Address::generate(&env);
+    let leaf_1 = get_leaf(&env, 0, &recipient_1, 100);
+    let leaf_2 = get_leaf(&env, 1, &recipient_2, 200);
+    let root = hash_pair(&env, &leaf_1, &leaf_2);
+
+    // The leaves add up to more than the distribution amount
+    let id = splitter.create_merkle_distribution(&token_address, &250, &root, &100);
+    splitter.claim_merkle_allocation(&id, &1, &recipient_2, &200, &vec![&env, leaf_1]);
+
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(&id, &0, &recipient_1, &100, &vec![&env, leaf_2]),
+        Err(Ok(Error::MerkleDistributionExhausted))
+    );
+}
+
+#[test]
+fn test_prorated_claim() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &400);
+
+    let recipient_1 = Address::generate(&env);
+    let recipient_2 = Address::generate(&env);
+    let leaf_1 = get_leaf(&env, 0, &recipient_1, 300);
+    let leaf_2 = get_leaf(&env, 1, &recipient_2, 300);
+    let root = hash_pair(&env, &leaf_1, &leaf_2);
+    let id = splitter.create_merkle_distribution(&token_address, &600, &root, &100);
+
+    // Half of the balance is removed outside of the contract
+    token.burn(&splitter_address, &500);
+
+    // Claims are prorated like the withdrawals of the shareholders
+    splitter.claim_merkle_allocation(&id, &0, &recipient_1, &300, &vec![&env, leaf_2]);
+    assert_eq!(token.balance(&recipient_1), 150);
+    assert_eq!(
+        splitter.get_lifetime_totals(&recipient_1, &token_address),
+        LifetimeTotalsDataKey {
+            distributed: 300,
+            withdrawn: 150,
+            written_off: 150,
+        }
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(&1, &0, &Address::generate(&env), &100, &vec![&env]),
+        Err(Ok(Error::NotInitialized))
+    );
+}
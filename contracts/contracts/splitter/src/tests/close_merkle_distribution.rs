@@ -0,0 +1,92 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    // Single leaf tree, the root is the leaf itself
+    let recipient = Address::generate(&env);
+    let root = env
+        .crypto()
+        .sha256(&(0u32, recipient.clone(), 100i128).to_xdr(&env));
+    let id = splitter.create_merkle_distribution(&token_address, &600, &root, &100);
+    splitter.claim_merkle_allocation(&id, &0, &recipient, &100, &vec![&env]);
+    assert_eq!(token.balance(&recipient), 100);
+    assert_eq!(splitter.get_unused_tokens(&token_address), 400);
+
+    // Recipients can claim until the expiry ledger
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_close_merkle_distribution(&id),
+        Err(Ok(Error::MerkleDistributionNotExpired))
+    );
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(splitter.close_merkle_distribution(&id), 500);
+    assert!(splitter.get_merkle_distribution(&id).closed);
+
+    // The unclaimed amount is back in the unused balance
+    assert_eq!(splitter.get_unused_tokens(&token_address), 900);
+    splitter.transfer_tokens(&token_address, &admin, &900);
+    assert_eq!(token.balance(&admin), 900);
+
+    assert_eq!(
+        splitter.try_close_merkle_distribution(&id),
+        Err(Ok(Error::MerkleDistributionClosed))
+    );
+    assert_eq!(
+        splitter.try_claim_merkle_allocation(&id, &0, &recipient, &100, &vec![&env]),
+        Err(Ok(Error::MerkleDistributionClosed))
+    );
+}
+
+#[test]
+fn test_distribution_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_close_merkle_distribution(&1),
+        Err(Ok(Error::MerkleDistributionNotFound))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_close_merkle_distribution(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert!(splitter.try_close_merkle_distribution(&1).is_err());
+}
@@ -0,0 +1,179 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, BytesN, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::merkle::MerkleDistributionDataKey,
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_protocol_fee,
+        create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let root = BytesN::from_array(&env, &[1; 32]);
+    assert_eq!(
+        splitter.create_merkle_distribution(&token_address, &600, &root, &100),
+        1
+    );
+    assert_eq!(
+        splitter.get_merkle_distribution(&1),
+        MerkleDistributionDataKey {
+            id: 1,
+            token: token_address.clone(),
+            root,
+            amount: 600,
+            claimed: 0,
+            reserved: 600,
+            expiry: 100,
+            closed: false,
+        }
+    );
+
+    // Reserved tokens are not part of the unused balance
+    assert_eq!(splitter.get_unused_tokens(&token_address), 400);
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &500),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        splitter.try_transfer_tokens(&token_address, &admin, &500),
+        Err(Ok(Error::TransferAmountAboveUnusedBalance))
+    );
+    assert_eq!(
+        splitter.create_merkle_distribution(
+            &token_address,
+            &400,
+            &BytesN::from_array(&env, &[2; 32]),
+            &100
+        ),
+        2
+    );
+    assert_eq!(splitter.get_unused_tokens(&token_address), 0);
+}
+
+#[test]
+fn test_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (splitter, splitter_address) =
+        create_splitter_with_protocol_fee(&env, &admin, &fee_recipient, 100);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    // The fee is taken on top of the reserved amount
+    assert_eq!(
+        splitter.try_create_merkle_distribution(
+            &token_address,
+            &995,
+            &BytesN::from_array(&env, &[1; 32]),
+            &100
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    splitter.create_merkle_distribution(
+        &token_address,
+        &600,
+        &BytesN::from_array(&env, &[1; 32]),
+        &100,
+    );
+    assert_eq!(splitter.get_allocation(&fee_recipient, &token_address), 6);
+    assert_eq!(splitter.get_unused_tokens(&token_address), 394);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_create_merkle_distribution(
+            &Address::generate(&env),
+            &100,
+            &BytesN::from_array(&env, &[0; 32]),
+            &100
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_get_merkle_distribution(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_create_merkle_distribution(
+            &Address::generate(&env),
+            &100,
+            &BytesN::from_array(&env, &[0; 32]),
+            &100,
+        )
+        .is_err());
+}
+
+#[test]
+fn test_invalid_amounts() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let root = BytesN::from_array(&env, &[0; 32]);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    assert_eq!(
+        splitter.try_create_merkle_distribution(&token_address, &100, &root, &100),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    assert_eq!(
+        splitter.try_create_merkle_distribution(&token_address, &0, &root, &100),
+        Err(Ok(Error::ZeroTransferAmount))
+    );
+
+    // The expiry cannot be in the past
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+    assert_eq!(
+        splitter.try_create_merkle_distribution(&token_address, &100, &root, &99),
+        Err(Ok(Error::InvalidMerkleExpiry))
+    );
+
+    // Allocated tokens cannot be reserved, rounding leaves 501 unused
+    splitter.distribute_tokens(&token_address, &500);
+    assert_eq!(
+        splitter.try_create_merkle_distribution(&token_address, &502, &root, &100),
+        Err(Ok(Error::InsufficientBalance))
+    );
+    assert_eq!(
+        splitter.try_get_merkle_distribution(&1),
+        Err(Ok(Error::MerkleDistributionNotFound))
+    );
+}
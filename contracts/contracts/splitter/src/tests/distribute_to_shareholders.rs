@@ -0,0 +1,160 @@
+use soroban_sdk::{testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, Env};
+use sorosplits_utils::types::ShareDataKey;
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_splitter_with_default_shares, create_splitter_with_shares, create_token,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let shareholder_3 = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &Bytes::from_slice(&env, "Splitter Contract".as_bytes()),
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 5000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 3000,
+            },
+            ShareDataKey {
+                shareholder: shareholder_3.clone(),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1000);
+
+    // Only the first two shareholders split the amount with their re-normalized shares
+    splitter.distribute_to_shareholders(
+        &token_address,
+        &800,
+        &vec![&env, shareholder_1.clone(), shareholder_2.clone()],
+    );
+
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 500);
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 300);
+    assert_eq!(splitter.get_allocation(&shareholder_3, &token_address), 0);
+    assert_eq!(splitter.get_unused_tokens(&token_address), 200);
+
+    // The round records the shares that were used for the split
+    assert_eq!(
+        splitter.get_distribution(&1).shares_hash,
+        env.crypto().sha256(
+            &vec![
+                &env,
+                ShareDataKey {
+                    shareholder: shareholder_1,
+                    share: 5000,
+                },
+                ShareDataKey {
+                    shareholder: shareholder_2,
+                    share: 3000,
+                },
+            ]
+            .to_xdr(&env)
+        )
+    );
+}
+
+#[test]
+fn test_duplicate_shareholder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+    let shareholder = splitter.list_shares().get(0).unwrap().shareholder;
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1000);
+
+    assert_eq!(
+        splitter.try_distribute_to_shareholders(
+            &token_address,
+            &1000,
+            &vec![&env, shareholder.clone(), shareholder]
+        ),
+        Err(Ok(Error::DuplicateShareholder))
+    );
+}
+
+#[test]
+fn test_not_shareholder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1000);
+
+    assert_eq!(
+        splitter.try_distribute_to_shareholders(
+            &token_address,
+            &1000,
+            &vec![&env, Address::generate(&env)]
+        ),
+        Err(Ok(Error::NotShareholder))
+    );
+}
+
+#[test]
+fn test_no_shareholders() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1000);
+
+    assert_eq!(
+        splitter.try_distribute_to_shareholders(&token_address, &1000, &vec![&env]),
+        Err(Ok(Error::NoOptedInShareholders))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let admin = Address::generate(&env);
+    let (splitter, _) = create_splitter_with_default_shares(&env, &admin);
+
+    assert!(splitter
+        .try_distribute_to_shareholders(&Address::generate(&env), &1000, &vec![&env])
+        .is_err());
+}
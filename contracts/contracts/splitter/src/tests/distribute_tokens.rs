@@ -1,11 +1,17 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
-use sorosplits_utils::types::ShareDataKey;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, Bytes, Env,
+};
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
 
 use crate::{
     errors::Error,
+    storage::{config::ProtocolFeeConfig, rounds::DistributionRoundDataKey},
     tests::helpers::{
         create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
-        create_token,
+        create_token, get_default_share_data,
     },
 };
 
@@ -51,6 +57,124 @@ fn happy_path() {
     assert_eq!(allocation_2, 195_000_000);
 }
 
+#[test]
+fn test_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shareholder_1 = Address::generate(&env);
+    let shareholder_2 = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+
+    let (splitter, splitter_address) = create_splitter(&env);
+    splitter.init_splitter(
+        &admin,
+        &name,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: shareholder_1.clone(),
+                share: 8050,
+            },
+            ShareDataKey {
+                shareholder: shareholder_2.clone(),
+                share: 1950,
+            },
+        ],
+        &true,
+        &Some(ProtocolFeeDataKey {
+            recipient: fee_recipient.clone(),
+            fee: 100,
+        }),
+    );
+
+    assert_eq!(
+        splitter.get_config().protocol_fee,
+        ProtocolFeeConfig::Enabled(ProtocolFeeDataKey {
+            recipient: fee_recipient.clone(),
+            fee: 100,
+        })
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+
+    let fee_allocation = splitter.get_allocation(&fee_recipient, &token_address);
+    assert_eq!(fee_allocation, 10_000_000);
+    let allocation_1 = splitter.get_allocation(&shareholder_1, &token_address);
+    assert_eq!(allocation_1, 796_950_000);
+    let allocation_2 = splitter.get_allocation(&shareholder_2, &token_address);
+    assert_eq!(allocation_2, 193_050_000);
+}
+
+#[test]
+fn test_distribution_rounds() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shares = get_default_share_data(&env);
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &shares, &true);
+
+    let (_, sudo_token_1, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, sudo_token_2, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+    sudo_token_1.mint(&splitter_address, &3_000);
+    sudo_token_2.mint(&splitter_address, &1_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 10);
+    splitter.distribute_tokens(&token_address_1, &1_000);
+    splitter.distribute_tokens(&token_address_2, &1_000);
+
+    let new_shares = get_default_share_data(&env);
+    splitter.update_shares(&new_shares);
+    env.ledger().with_mut(|li| li.sequence_number = 20);
+    splitter.distribute_tokens(&token_address_1, &2_000);
+
+    assert_eq!(
+        splitter.get_distribution(&1),
+        DistributionRoundDataKey {
+            id: 1,
+            token: token_address_1.clone(),
+            amount: 1_000,
+            ledger: 10,
+            shares_hash: env.crypto().sha256(&shares.to_xdr(&env)),
+        }
+    );
+    assert_eq!(splitter.get_distribution(&2).token, token_address_2);
+
+    let rounds = splitter.list_distributions(&token_address_1, &0, &10);
+    assert_eq!(rounds.len(), 2);
+    assert_eq!(rounds.get(1).unwrap().id, 3);
+    assert_eq!(rounds.get(1).unwrap().ledger, 20);
+    assert_eq!(
+        rounds.get(1).unwrap().shares_hash,
+        env.crypto().sha256(&new_shares.to_xdr(&env))
+    );
+
+    let rounds = splitter.list_distributions(&token_address_1, &1, &10);
+    assert_eq!(rounds.len(), 1);
+    assert_eq!(rounds.get(0).unwrap().id, 3);
+
+    assert_eq!(
+        splitter.try_get_distribution(&4),
+        Err(Ok(Error::RoundNotFound))
+    );
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
@@ -130,6 +254,28 @@ fn test_distribution_amount_above_balance() {
     );
 }
 
+#[test]
+fn test_distribution_amount_above_unused_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000);
+    splitter.distribute_tokens(&token_address, &600_000);
+
+    // Allocated tokens cannot be distributed again
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &600_000),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
 #[test]
 fn test_token_not_whitelisted() {
     let env = Env::default();
@@ -145,3 +291,25 @@ fn test_token_not_whitelisted() {
         Err(Ok(Error::TokenNotWhitelisted))
     );
 }
+
+#[test]
+fn test_distribution_amount_below_minimum() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    splitter.update_min_distribution(&token_address, &1_000_000);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &999_999),
+        Err(Ok(Error::DistributionAmountBelowMinimum))
+    );
+    splitter.distribute_tokens(&token_address, &1_000_000);
+}
@@ -1,5 +1,5 @@
 use soroban_sdk::{testutils::Address as _, token, vec, Address, Bytes, Env, Vec};
-use sorosplits_utils::types::ShareDataKey;
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
 use token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
 
 use crate::contract::{Splitter, SplitterClient};
@@ -17,7 +17,7 @@ pub fn create_splitter_with_shares<'a>(
     updatable: &bool,
 ) -> (SplitterClient<'a>, Address) {
     let (client, contract_id) = create_splitter(e);
-    client.init_splitter(admin, name, shares, updatable);
+    client.init_splitter(admin, name, shares, updatable, &None);
     (client, contract_id)
 }
 
@@ -45,6 +45,26 @@ pub fn create_splitter_with_default_shares<'a>(
     (client, contract_id)
 }
 
+pub fn create_splitter_with_protocol_fee<'a>(
+    e: &'a Env,
+    admin: &Address,
+    fee_recipient: &Address,
+    fee: i128,
+) -> (SplitterClient<'a>, Address) {
+    let (client, contract_id) = create_splitter(e);
+    client.init_splitter(
+        admin,
+        &Bytes::from_slice(&e, "Splitter Contract".as_bytes()),
+        &get_default_share_data(e),
+        &true,
+        &Some(ProtocolFeeDataKey {
+            recipient: fee_recipient.clone(),
+            fee,
+        }),
+    );
+    (client, contract_id)
+}
+
 pub fn create_token<'a>(
     e: &Env,
     admin: &Address,
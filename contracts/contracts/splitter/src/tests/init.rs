@@ -1,7 +1,11 @@
 use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
-use sorosplits_utils::types::ShareDataKey;
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
 
-use crate::{errors::Error, tests::helpers::create_splitter};
+use crate::{
+    errors::Error,
+    storage::config::ProtocolFeeConfig,
+    tests::helpers::{create_splitter, get_default_share_data},
+};
 
 #[test]
 fn happy_path() {
@@ -24,11 +28,15 @@ fn happy_path() {
         },
     ];
 
-    splitter.init_splitter(&admin, &name, &shares, &true);
+    splitter.init_splitter(&admin, &name, &shares, &true, &None);
 
     assert_eq!(splitter.get_share(&shareholder_1), Some(8050));
     assert_eq!(splitter.get_share(&shareholder_2), Some(1950));
     assert_eq!(splitter.list_shares(), shares);
+    assert_eq!(
+        splitter.get_config().protocol_fee,
+        ProtocolFeeConfig::Disabled
+    );
 }
 
 #[test]
@@ -49,10 +57,10 @@ fn test_already_initialized() {
             share: 1950,
         },
     ];
-    splitter.init_splitter(&admin, &name, &shares, &true);
+    splitter.init_splitter(&admin, &name, &shares, &true, &None);
 
     assert_eq!(
-        splitter.try_init_splitter(&admin, &name, &shares, &true),
+        splitter.try_init_splitter(&admin, &name, &shares, &true, &None),
         Err(Ok(Error::AlreadyInitialized))
     );
 }
@@ -73,7 +81,7 @@ fn test_low_share_count() {
     ];
 
     assert_eq!(
-        splitter.try_init_splitter(&admin, &name, &shares, &true),
+        splitter.try_init_splitter(&admin, &name, &shares, &true, &None),
         Err(Ok(Error::LowShareCount))
     );
 }
@@ -101,7 +109,8 @@ fn test_invalid_share_total() {
                     share: 8050,
                 },
             ],
-            &true
+            &true,
+            &None
         ),
         Err(Ok(Error::InvalidShareTotal))
     );
@@ -121,8 +130,47 @@ fn test_invalid_share_total() {
                     share: 50,
                 },
             ],
-            &true
+            &true,
+            &None
         ),
         Err(Ok(Error::InvalidShareTotal))
     );
 }
+
+#[test]
+fn test_invalid_protocol_fee() {
+    let env: Env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shares = get_default_share_data(&env);
+
+    assert_eq!(
+        splitter.try_init_splitter(
+            &admin,
+            &name,
+            &shares,
+            &true,
+            &Some(ProtocolFeeDataKey {
+                recipient: Address::generate(&env),
+                fee: 501,
+            })
+        ),
+        Err(Ok(Error::InvalidProtocolFee))
+    );
+
+    assert_eq!(
+        splitter.try_init_splitter(
+            &admin,
+            &name,
+            &shares,
+            &true,
+            &Some(ProtocolFeeDataKey {
+                recipient: Address::generate(&env),
+                fee: -1,
+            })
+        ),
+        Err(Ok(Error::InvalidProtocolFee))
+    );
+}
@@ -0,0 +1,145 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Bytes, Env,
+};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token, get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder_1 = share_data.get(0).unwrap().shareholder;
+    let shareholder_2 = share_data.get(1).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    splitter.update_distribution_schedule(&token_address, &1000, &100);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    let keeper = Address::generate(&env);
+    env.ledger().with_mut(|li| li.sequence_number = 1100);
+    splitter.poke(&token_address, &keeper);
+
+    // 1% of the unused balance goes to the keeper
+    assert_eq!(token.balance(&keeper), 10_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        796_950_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        193_050_000
+    );
+    assert_eq!(splitter.get_unused_tokens(&token_address), 0);
+    assert_eq!(
+        splitter
+            .get_distribution_schedule(&token_address)
+            .unwrap()
+            .last_distribution,
+        1100
+    );
+
+    // Only the new deposit is distributed on the next poke
+    sudo_token.mint(&splitter_address, &100_000_000);
+    env.ledger().with_mut(|li| li.sequence_number = 2100);
+    splitter.poke(&token_address, &keeper);
+
+    assert_eq!(token.balance(&keeper), 11_000_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address),
+        876_645_000
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address),
+        212_355_000
+    );
+    assert_eq!(splitter.get_unused_tokens(&token_address), 0);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_poke(&Address::generate(&env), &Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_schedule_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    assert_eq!(
+        splitter.try_poke(&token_address, &Address::generate(&env)),
+        Err(Ok(Error::ScheduleNotFound))
+    );
+}
+
+#[test]
+fn test_interval_not_elapsed() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    splitter.update_distribution_schedule(&token_address, &1000, &0);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1099);
+    assert_eq!(
+        splitter.try_poke(&token_address, &Address::generate(&env)),
+        Err(Ok(Error::ScheduleIntervalNotElapsed))
+    );
+}
+
+#[test]
+fn test_zero_unused_balance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    splitter.update_distribution_schedule(&token_address, &1000, &0);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+
+    env.ledger().with_mut(|li| li.sequence_number = 1100);
+    assert_eq!(
+        splitter.try_poke(&token_address, &Address::generate(&env)),
+        Err(Ok(Error::ZeroTransferAmount))
+    );
+}
@@ -0,0 +1,149 @@
+use soroban_sdk::{testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, Env};
+
+use crate::{
+    errors::Error,
+    storage::history::LifetimeTotalsDataKey,
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token, get_default_share_data,
+    },
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder_1 = share_data.get(0).unwrap().shareholder;
+    let shareholder_2 = share_data.get(1).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &1_000);
+
+    // Half of the balance is removed outside of the contract
+    token.burn(&splitter_address, &500);
+
+    splitter.reconcile_allocations(&token_address);
+
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 402);
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address), 97);
+    assert_eq!(splitter.get_token_balance(&token_address).shortfall, 0);
+
+    // The reduction of the allocations is written off in the lifetime totals
+    assert_eq!(
+        splitter.get_lifetime_totals(&shareholder_1, &token_address),
+        LifetimeTotalsDataKey {
+            distributed: 805,
+            withdrawn: 0,
+            written_off: 403,
+        }
+    );
+    assert_eq!(
+        splitter.get_token_lifetime_totals(&token_address),
+        LifetimeTotalsDataKey {
+            distributed: 1_000,
+            withdrawn: 0,
+            written_off: 501,
+        }
+    );
+
+    // Reconciled allocations are withdrawn in full
+    splitter.withdraw_allocation(&token_address, &shareholder_1, &402);
+    assert_eq!(token.balance(&shareholder_1), 402);
+}
+
+#[test]
+fn test_reserved_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder_1 = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &400);
+
+    let recipient = Address::generate(&env);
+    let root = env
+        .crypto()
+        .sha256(&(0u32, recipient.clone(), 600i128).to_xdr(&env));
+    let id = splitter.create_merkle_distribution(&token_address, &600, &root, &100);
+
+    // The balance covers the allocations but not the reserved amount
+    token.burn(&splitter_address, &500);
+    assert_eq!(splitter.get_token_balance(&token_address).shortfall, 500);
+
+    splitter.reconcile_allocations(&token_address);
+
+    // The allocations and the reserved amount are both halved
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 161);
+    assert_eq!(splitter.get_merkle_distribution(&id).reserved, 300);
+    assert_eq!(splitter.get_token_balance(&token_address).shortfall, 0);
+
+    // Reconciled claims and allocations are paid without a second haircut
+    splitter.claim_merkle_allocation(&id, &0, &recipient, &600, &vec![&env]);
+    assert_eq!(token.balance(&recipient), 300);
+    splitter.withdraw_allocation(&token_address, &shareholder_1, &161);
+    assert_eq!(token.balance(&shareholder_1), 161);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_reconcile_allocations(&Address::generate(&env)),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert!(splitter
+        .try_reconcile_allocations(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_no_allocation_shortfall() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &1_000);
+
+    assert_eq!(
+        splitter.try_reconcile_allocations(&token_address),
+        Err(Ok(Error::NoAllocationShortfall))
+    );
+}
@@ -0,0 +1,215 @@
+extern crate std;
+
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec,
+    xdr::ToXdr,
+    Address, BytesN, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::{history::LifetimeTotalsDataKey, vouchers::VoucherDataKey},
+    tests::helpers::{
+        create_splitter, create_splitter_with_default_shares, create_splitter_with_protocol_fee,
+        create_token,
+    },
+};
+
+fn sign_voucher(env: &Env, signing_key: &SigningKey, voucher: &VoucherDataKey) -> BytesN<64> {
+    let message: std::vec::Vec<u8> = voucher.clone().to_xdr(env).iter().collect();
+    BytesN::from_array(env, &signing_key.sign(&message).to_bytes())
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    let public_key = BytesN::from_array(&env, &signing_key.verifying_key().to_bytes());
+    splitter.update_voucher_signer(&Some(public_key.clone()));
+    assert_eq!(splitter.get_voucher_signer(), Some(public_key));
+
+    let recipient = Address::generate(&env);
+    let voucher = VoucherDataKey {
+        contract: splitter_address.clone(),
+        recipient: recipient.clone(),
+        token: token_address.clone(),
+        amount: 400,
+        nonce: 1,
+        expiry: 100,
+    };
+    let signature = sign_voucher(&env, &signing_key, &voucher);
+
+    assert!(!splitter.is_voucher_redeemed(&1));
+    splitter.redeem_voucher(&voucher, &signature);
+    assert!(splitter.is_voucher_redeemed(&1));
+
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(splitter.get_unused_tokens(&token_address), 600);
+    assert_eq!(
+        splitter.get_lifetime_totals(&recipient, &token_address),
+        LifetimeTotalsDataKey {
+            distributed: 400,
+            withdrawn: 400,
+            written_off: 0,
+        }
+    );
+
+    // Nonces can't be replayed
+    assert_eq!(
+        splitter.try_redeem_voucher(&voucher, &signature),
+        Err(Ok(Error::VoucherAlreadyRedeemed))
+    );
+
+    // Removing the signer disables the vouchers
+    splitter.update_voucher_signer(&None);
+    let voucher = VoucherDataKey {
+        nonce: 2,
+        ..voucher
+    };
+    let signature = sign_voucher(&env, &signing_key, &voucher);
+    assert_eq!(
+        splitter.try_redeem_voucher(&voucher, &signature),
+        Err(Ok(Error::VoucherSignerNotSet))
+    );
+}
+
+#[test]
+fn test_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let (splitter, splitter_address) =
+        create_splitter_with_protocol_fee(&env, &admin, &fee_recipient, 100);
+
+    let (token, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    splitter.update_voucher_signer(&Some(BytesN::from_array(
+        &env,
+        &signing_key.verifying_key().to_bytes(),
+    )));
+
+    let recipient = Address::generate(&env);
+    let voucher = VoucherDataKey {
+        contract: splitter_address.clone(),
+        recipient: recipient.clone(),
+        token: token_address.clone(),
+        amount: 400,
+        nonce: 1,
+        expiry: 100,
+    };
+    splitter.redeem_voucher(&voucher, &sign_voucher(&env, &signing_key, &voucher));
+
+    // The recipient gets the signed amount and the fee is taken on top of it
+    assert_eq!(token.balance(&recipient), 400);
+    assert_eq!(splitter.get_allocation(&fee_recipient, &token_address), 4);
+    assert_eq!(splitter.get_unused_tokens(&token_address), 596);
+
+    // The fee has to be covered by the unused balance as well
+    let voucher = VoucherDataKey {
+        amount: 595,
+        nonce: 2,
+        ..voucher
+    };
+    assert_eq!(
+        splitter.try_redeem_voucher(&voucher, &sign_voucher(&env, &signing_key, &voucher)),
+        Err(Ok(Error::InsufficientBalance))
+    );
+}
+
+#[test]
+fn test_invalid_vouchers() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    let signing_key = SigningKey::from_bytes(&[1; 32]);
+    splitter.update_voucher_signer(&Some(BytesN::from_array(
+        &env,
+        &signing_key.verifying_key().to_bytes(),
+    )));
+
+    let voucher = VoucherDataKey {
+        contract: splitter_address.clone(),
+        recipient: Address::generate(&env),
+        token: token_address.clone(),
+        amount: 400,
+        nonce: 1,
+        expiry: 100,
+    };
+
+    // Voucher signed for another contract
+    let other_voucher = VoucherDataKey {
+        contract: Address::generate(&env),
+        ..voucher.clone()
+    };
+    assert_eq!(
+        splitter.try_redeem_voucher(
+            &other_voucher,
+            &sign_voucher(&env, &signing_key, &other_voucher)
+        ),
+        Err(Ok(Error::InvalidVoucher))
+    );
+
+    // Amount above the unused balance
+    let large_voucher = VoucherDataKey {
+        amount: 1_001,
+        ..voucher.clone()
+    };
+    assert_eq!(
+        splitter.try_redeem_voucher(
+            &large_voucher,
+            &sign_voucher(&env, &signing_key, &large_voucher)
+        ),
+        Err(Ok(Error::InsufficientBalance))
+    );
+
+    // Signature doesn't match the voucher
+    let signature = sign_voucher(&env, &signing_key, &voucher);
+    assert!(splitter
+        .try_redeem_voucher(&large_voucher, &signature)
+        .is_err());
+    assert!(!splitter.is_voucher_redeemed(&1));
+
+    env.ledger().with_mut(|li| li.sequence_number = 101);
+    assert_eq!(
+        splitter.try_redeem_voucher(&voucher, &signature),
+        Err(Ok(Error::VoucherExpired))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_voucher_signer(&None),
+        Err(Ok(Error::NotInitialized))
+    );
+    assert_eq!(
+        splitter.try_is_voucher_redeemed(&1),
+        Err(Ok(Error::NotInitialized))
+    );
+}
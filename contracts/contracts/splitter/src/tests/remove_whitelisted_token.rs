@@ -0,0 +1,88 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::distributions::WhitelistedTokenDataKey,
+    tests::helpers::{create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+
+    splitter.remove_whitelisted_token(&token_address_1);
+
+    assert_eq!(
+        splitter.list_whitelisted_tokens(),
+        vec![
+            &env,
+            WhitelistedTokenDataKey {
+                token: token_address_2,
+                min_distribution: 0,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert!(splitter
+        .try_remove_whitelisted_token(&Address::generate(&env))
+        .is_err());
+}
+
+#[test]
+fn test_token_not_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_remove_whitelisted_token(&Address::generate(&env)),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_token_has_allocations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.add_whitelisted_token(&token_address);
+
+    sudo_token.mint(&splitter_address, &1_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000);
+
+    assert_eq!(
+        splitter.try_remove_whitelisted_token(&token_address),
+        Err(Ok(Error::TokenHasAllocations))
+    );
+
+    // The token can be removed once every allocation is withdrawn
+    for share in splitter.list_shares().iter() {
+        let allocation = splitter.get_allocation(&share.shareholder, &token_address);
+        splitter.withdraw_allocation(&token_address, &share.shareholder, &allocation);
+    }
+    splitter.remove_whitelisted_token(&token_address);
+    assert_eq!(splitter.list_whitelisted_tokens(), vec![&env]);
+}
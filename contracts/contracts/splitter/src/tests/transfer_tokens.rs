@@ -1,4 +1,4 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env, String};
 
 use crate::{
     errors::Error,
@@ -130,3 +130,64 @@ fn test_transfer_amount_unused_balance() {
         Err(Ok(Error::TransferAmountAboveUnusedBalance))
     );
 }
+
+#[test]
+fn test_transfer_dewhitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &get_default_share_data(&env), &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &400_000_000);
+
+    // Remove the token from the whitelist while the allocations exist
+    splitter.update_whitelisted_tokens(&vec![&env]);
+
+    // Only the unused balance can be recovered
+    let recipient = Address::generate(&env);
+    assert_eq!(
+        splitter.try_transfer_tokens(&token_address, &recipient, &600_000_001),
+        Err(Ok(Error::TransferAmountAboveUnusedBalance))
+    );
+    splitter.transfer_tokens(&token_address, &recipient, &600_000_000);
+    assert_eq!(token.balance(&recipient), 600_000_000);
+    assert_eq!(token.balance(&splitter_address), 400_000_000);
+}
+
+#[test]
+fn test_token_transfer_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &Address::generate(&env),
+        &Bytes::from_slice(&env, "Splitter Contract".as_bytes()),
+        &get_default_share_data(&env),
+        &true,
+    );
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+
+    // Stellar account without a trustline for the token
+    let recipient = Address::from_string(&String::from_str(
+        &env,
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+    ));
+
+    assert_eq!(
+        splitter.try_transfer_tokens(&token_address, &recipient, &1_000_000_000),
+        Err(Ok(Error::TokenTransferUnauthorized))
+    );
+}
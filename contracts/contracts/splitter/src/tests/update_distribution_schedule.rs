@@ -0,0 +1,121 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger},
+    vec, Address, Env,
+};
+
+use crate::{
+    errors::Error,
+    storage::schedules::ScheduleDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+    env.ledger().with_mut(|li| li.sequence_number = 100);
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    splitter.update_distribution_schedule(&token_address, &1000, &50);
+    assert_eq!(
+        splitter.get_distribution_schedule(&token_address),
+        Some(ScheduleDataKey {
+            interval: 1000,
+            keeper_reward: 50,
+            last_distribution: 100,
+        })
+    );
+
+    // Updating the schedule keeps the last distribution ledger
+    env.ledger().with_mut(|li| li.sequence_number = 200);
+    splitter.update_distribution_schedule(&token_address, &500, &0);
+    assert_eq!(
+        splitter.get_distribution_schedule(&token_address),
+        Some(ScheduleDataKey {
+            interval: 500,
+            keeper_reward: 0,
+            last_distribution: 100,
+        })
+    );
+
+    splitter.remove_distribution_schedule(&token_address);
+    assert_eq!(splitter.get_distribution_schedule(&token_address), None);
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_distribution_schedule(&Address::generate(&env), &1000, &50),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert!(splitter
+        .try_update_distribution_schedule(&Address::generate(&env), &1000, &50)
+        .is_err());
+}
+
+#[test]
+fn test_token_not_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_update_distribution_schedule(&token_address, &1000, &50),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_invalid_schedule() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    assert_eq!(
+        splitter.try_update_distribution_schedule(&token_address, &0, &50),
+        Err(Ok(Error::InvalidScheduleInterval))
+    );
+    assert_eq!(
+        splitter.try_update_distribution_schedule(&token_address, &1000, &101),
+        Err(Ok(Error::InvalidKeeperReward))
+    );
+    assert_eq!(
+        splitter.try_update_distribution_schedule(&token_address, &1000, &-1),
+        Err(Ok(Error::InvalidKeeperReward))
+    );
+}
+
+#[test]
+fn test_remove_schedule_not_found() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_remove_distribution_schedule(&Address::generate(&env)),
+        Err(Ok(Error::ScheduleNotFound))
+    );
+}
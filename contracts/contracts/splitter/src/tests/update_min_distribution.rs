@@ -0,0 +1,84 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+use crate::{
+    errors::Error,
+    storage::distributions::WhitelistedTokenDataKey,
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    splitter.update_min_distribution(&token_address, &1_000_000);
+
+    assert_eq!(
+        splitter.list_whitelisted_tokens(),
+        vec![
+            &env,
+            WhitelistedTokenDataKey {
+                token: token_address,
+                min_distribution: 1_000_000,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_min_distribution(&Address::generate(&env), &1),
+        Err(Ok(Error::NotInitialized))
+    );
+}
+
+#[test]
+fn test_unauthorized() {
+    let env = Env::default();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    assert!(splitter
+        .try_update_min_distribution(&Address::generate(&env), &1)
+        .is_err());
+}
+
+#[test]
+fn test_token_not_whitelisted() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+
+    assert_eq!(
+        splitter.try_update_min_distribution(&token_address, &1),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
+}
+
+#[test]
+fn test_invalid_min_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    assert_eq!(
+        splitter.try_update_min_distribution(&token_address, &-1),
+        Err(Ok(Error::InvalidMinDistribution))
+    );
+}
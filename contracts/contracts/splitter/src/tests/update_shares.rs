@@ -5,6 +5,7 @@ use crate::{
     errors::Error,
     tests::helpers::{
         create_splitter, create_splitter_with_default_shares, create_splitter_with_shares,
+        create_token, get_default_share_data,
     },
 };
 
@@ -65,6 +66,101 @@ fn test_happy_path() {
     assert_eq!(splitter.get_share(&old_shareholder_2), None);
 }
 
+#[test]
+fn test_auto_distribute() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let old_shareholder_1 = Address::generate(&env);
+    let old_shareholder_2 = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_shares(
+        &env,
+        &admin,
+        &name,
+        &vec![
+            &env,
+            ShareDataKey {
+                shareholder: old_shareholder_1.clone(),
+                share: 8000,
+            },
+            ShareDataKey {
+                shareholder: old_shareholder_2.clone(),
+                share: 2000,
+            },
+        ],
+        &true,
+    );
+
+    let (_, sudo_token_1, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+    splitter.update_min_distribution(&token_address_1, &10_000);
+    splitter.update_auto_distribute(&true);
+    assert!(splitter.get_auto_distribute());
+
+    sudo_token_1.mint(&splitter_address, &1_000);
+
+    let new_shareholder = Address::generate(&env);
+    splitter.update_shares(&vec![
+        &env,
+        ShareDataKey {
+            shareholder: old_shareholder_1.clone(),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: new_shareholder.clone(),
+            share: 5000,
+        },
+    ]);
+
+    // The deposit is split by the old shares, ignoring the minimum distribution amount
+    assert_eq!(
+        splitter.get_allocation(&old_shareholder_1, &token_address_1),
+        800
+    );
+    assert_eq!(
+        splitter.get_allocation(&old_shareholder_2, &token_address_1),
+        200
+    );
+    assert_eq!(
+        splitter.get_allocation(&new_shareholder, &token_address_1),
+        0
+    );
+    assert_eq!(splitter.get_unused_tokens(&token_address_1), 0);
+    assert_eq!(
+        splitter.list_distributions(&token_address_1, &0, &10).len(),
+        1
+    );
+    assert_eq!(
+        splitter.list_distributions(&token_address_2, &0, &10).len(),
+        0
+    );
+}
+
+#[test]
+fn test_auto_distribute_disabled() {
+    let env: Env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let (splitter, splitter_address) = create_splitter_with_default_shares(&env, &admin);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    assert!(!splitter.get_auto_distribute());
+    splitter.update_shares(&get_default_share_data(&env));
+
+    assert_eq!(splitter.get_unused_tokens(&token_address), 1_000);
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
@@ -0,0 +1,266 @@
+use soroban_sdk::{testutils::Address as _, vec, xdr::ToXdr, Address, Bytes, Env, Vec};
+use sorosplits_utils::types::{ProtocolFeeDataKey, ShareDataKey};
+
+use crate::{
+    errors::Error,
+    tests::helpers::{create_splitter, create_splitter_with_shares, create_token},
+};
+
+fn get_share_data(env: &Env) -> Vec<ShareDataKey> {
+    vec![
+        env,
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 5000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 3000,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(env),
+            share: 2000,
+        },
+    ]
+}
+
+#[test]
+fn happy_path() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shares = get_share_data(&env);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_2 = shares.get(1).unwrap().shareholder;
+    let shareholder_3 = shares.get(2).unwrap().shareholder;
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &shares, &true);
+
+    let (_, sudo_token_1, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, sudo_token_2, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+    sudo_token_1.mint(&splitter_address, &2_000);
+    sudo_token_2.mint(&splitter_address, &1_000);
+
+    splitter.update_token_opt_out(&shareholder_2, &token_address_1, &true);
+    assert_eq!(
+        splitter.list_opt_outs(&shareholder_2),
+        vec![&env, token_address_1.clone()]
+    );
+
+    // The remaining 7000 basis points are re-normalized to split the whole amount
+    splitter.distribute_tokens(&token_address_1, &1_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address_1),
+        714
+    );
+    assert_eq!(splitter.get_allocation(&shareholder_2, &token_address_1), 0);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address_1),
+        285
+    );
+    // The round records the shares without the opted out shareholder
+    assert_eq!(
+        splitter.get_distribution(&1).shares_hash,
+        env.crypto()
+            .sha256(&vec![&env, shares.get(0).unwrap(), shares.get(2).unwrap()].to_xdr(&env))
+    );
+
+    // Opt outs only apply to the opted out token
+    splitter.distribute_tokens(&token_address_2, &1_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address_2),
+        500
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address_2),
+        300
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address_2),
+        200
+    );
+
+    // Opting back in restores the original shares
+    splitter.update_token_opt_out(&shareholder_2, &token_address_1, &false);
+    assert_eq!(splitter.list_opt_outs(&shareholder_2), vec![&env]);
+    splitter.distribute_tokens(&token_address_1, &1_000);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_1, &token_address_1),
+        1_214
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_2, &token_address_1),
+        300
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address_1),
+        485
+    );
+}
+
+#[test]
+fn test_opt_out_with_protocol_fee() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let fee_recipient = Address::generate(&env);
+    let shares = get_share_data(&env);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_3 = shares.get(2).unwrap().shareholder;
+
+    let (splitter, splitter_address) = create_splitter(&env);
+    splitter.init_splitter(
+        &admin,
+        &Bytes::from_slice(&env, "Splitter Contract".as_bytes()),
+        &shares,
+        &true,
+        &Some(ProtocolFeeDataKey {
+            recipient: fee_recipient.clone(),
+            fee: 100,
+        }),
+    );
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &10_000);
+
+    splitter.update_token_opt_out(&shareholder_1, &token_address, &true);
+    splitter.distribute_tokens(&token_address, &10_000);
+
+    // The fee is taken before the shares are re-normalized
+    assert_eq!(splitter.get_allocation(&fee_recipient, &token_address), 100);
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(
+        splitter.get_allocation(&shareholder_3, &token_address),
+        3_960
+    );
+}
+
+#[test]
+fn test_all_shareholders_opted_out() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shares = get_share_data(&env);
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &shares, &true);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    for share in shares.iter() {
+        splitter.update_token_opt_out(&share.shareholder, &token_address, &true);
+    }
+
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address, &1_000),
+        Err(Ok(Error::NoOptedInShareholders))
+    );
+}
+
+#[test]
+fn test_opt_outs_of_removed_shareholders_cleared() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let shares = get_share_data(&env);
+    let shareholder_1 = shares.get(0).unwrap().shareholder;
+    let shareholder_2 = shares.get(1).unwrap().shareholder;
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &shares, &true);
+
+    let (_, sudo_token, token_address) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+    sudo_token.mint(&splitter_address, &1_000);
+
+    splitter.update_token_opt_out(&shareholder_1, &token_address, &true);
+    splitter.update_token_opt_out(&shareholder_2, &token_address, &true);
+
+    // The first shareholder is kept and the second one is removed
+    splitter.update_shares(&vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_1.clone(),
+            share: 6000,
+        },
+        ShareDataKey {
+            shareholder: shares.get(2).unwrap().shareholder,
+            share: 4000,
+        },
+    ]);
+    // The retained shareholder keeps the opt-out
+    assert_eq!(
+        splitter.list_opt_outs(&shareholder_1),
+        vec![&env, token_address.clone()]
+    );
+    assert_eq!(splitter.list_opt_outs(&shareholder_2), vec![&env]);
+
+    splitter.distribute_tokens(&token_address, &1_000);
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+    assert_eq!(
+        splitter.get_allocation(&shares.get(2).unwrap().shareholder, &token_address),
+        1_000
+    );
+
+    // A removed shareholder that is added back starts without opt-outs
+    splitter.update_shares(&vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder_1.clone(),
+            share: 6000,
+        },
+        ShareDataKey {
+            shareholder: shareholder_2.clone(),
+            share: 4000,
+        },
+    ]);
+    assert_eq!(splitter.list_opt_outs(&shareholder_2), vec![&env]);
+}
+
+#[test]
+fn test_not_shareholder() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let (splitter, _) =
+        create_splitter_with_shares(&env, &admin, &name, &get_share_data(&env), &true);
+
+    assert_eq!(
+        splitter.try_update_token_opt_out(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &true
+        ),
+        Err(Ok(Error::NotShareholder))
+    );
+}
+
+#[test]
+fn test_not_initialized() {
+    let env = Env::default();
+    let (splitter, _) = create_splitter(&env);
+
+    assert_eq!(
+        splitter.try_update_token_opt_out(
+            &Address::generate(&env),
+            &Address::generate(&env),
+            &true
+        ),
+        Err(Ok(Error::NotInitialized))
+    );
+}
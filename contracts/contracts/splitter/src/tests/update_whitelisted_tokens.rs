@@ -1,6 +1,12 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, testutils::Address as _, vec, Address, Env, String,
+};
 
-use crate::tests::helpers::{create_splitter_with_default_shares, create_token};
+use crate::{
+    errors::Error,
+    storage::distributions::{TokenInfoDataKey, WhitelistedTokenDataKey},
+    tests::helpers::{create_splitter, create_splitter_with_default_shares, create_token},
+};
 
 #[test]
 fn happy_path() {
@@ -13,7 +19,129 @@ fn happy_path() {
     splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
 
     let whitelisted_tokens = splitter.list_whitelisted_tokens();
-    assert_eq!(whitelisted_tokens, vec![&env, token_address]);
+    assert_eq!(
+        whitelisted_tokens,
+        vec![
+            &env,
+            WhitelistedTokenDataKey {
+                token: token_address,
+                min_distribution: 0,
+            }
+        ]
+    );
+}
+
+#[test]
+fn test_keeps_min_distribution() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address_1.clone()]);
+    splitter.update_min_distribution(&token_address_1, &1_000);
+
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+
+    assert_eq!(
+        splitter.list_whitelisted_tokens(),
+        vec![
+            &env,
+            WhitelistedTokenDataKey {
+                token: token_address_1,
+                min_distribution: 1_000,
+            },
+            WhitelistedTokenDataKey {
+                token: token_address_2,
+                min_distribution: 0,
+            }
+        ]
+    );
+}
+
+/// Token that returns any name, including the name of a Stellar Asset Contract
+#[contract]
+pub struct SpoofedToken;
+
+#[contractimpl]
+impl SpoofedToken {
+    pub fn set_name(env: Env, name: String) {
+        env.storage().instance().set(&symbol_short!("name"), &name);
+    }
+
+    pub fn name(env: Env) -> String {
+        env.storage()
+            .instance()
+            .get(&symbol_short!("name"))
+            .unwrap()
+    }
+
+    pub fn symbol(env: Env) -> String {
+        String::from_str(&env, "aaa")
+    }
+
+    pub fn decimals(_env: Env) -> u32 {
+        7
+    }
+
+    pub fn balance(_env: Env, _id: Address) -> i128 {
+        0
+    }
+}
+
+#[test]
+fn test_token_info() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (token, _, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+
+    let token_info = splitter.list_whitelisted_token_info();
+    assert_eq!(token_info.len(), 2);
+    assert_eq!(
+        token_info.get(0).unwrap(),
+        TokenInfoDataKey {
+            token: token_address_1.clone(),
+            name: token.name(),
+            symbol: token.symbol(),
+            decimals: 7,
+            is_stellar_asset: true,
+        }
+    );
+
+    // Contracts that copy the name of a Stellar Asset Contract are not recognized as one
+    let spoofed_token_address = env.register_contract(None, SpoofedToken);
+    SpoofedTokenClient::new(&env, &spoofed_token_address).set_name(&token.name());
+    splitter.update_whitelisted_tokens(&vec![&env, spoofed_token_address.clone()]);
+    let token_info = splitter.list_whitelisted_token_info();
+    assert_eq!(token_info.get(0).unwrap().name, token.name());
+    assert!(!token_info.get(0).unwrap().is_stellar_asset);
+
+    splitter.update_whitelisted_tokens(&vec![
+        &env,
+        token_address_1.clone(),
+        token_address_2.clone(),
+    ]);
+
+    // Cached metadata is removed with the token
+    splitter.update_whitelisted_tokens(&vec![&env, token_address_2.clone()]);
+    let token_info = splitter.list_whitelisted_token_info();
+    assert_eq!(token_info.len(), 1);
+    assert_eq!(token_info.get(0).unwrap().token, token_address_2);
 }
 
 #[test]
@@ -34,7 +162,60 @@ fn test_invalid_token() {
 
     let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
 
-    assert!(splitter
-        .try_update_whitelisted_tokens(&vec![&env, Address::generate(&env)])
-        .is_err());
+    assert_eq!(
+        splitter.try_update_whitelisted_tokens(&vec![&env, Address::generate(&env)]),
+        Err(Ok(Error::InvalidToken))
+    );
+
+    // Contracts that don't implement the token interface are rejected
+    let (_, contract_address) = create_splitter(&env);
+    assert_eq!(
+        splitter.try_update_whitelisted_tokens(&vec![&env, contract_address]),
+        Err(Ok(Error::InvalidToken))
+    );
+}
+
+#[test]
+fn test_duplicate_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, _) = create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, _, token_address) = create_token(&env, &Address::generate(&env));
+    assert_eq!(
+        splitter.try_update_whitelisted_tokens(&vec![
+            &env,
+            token_address.clone(),
+            token_address.clone()
+        ]),
+        Err(Ok(Error::TokenAlreadyWhitelisted))
+    );
+}
+
+#[test]
+fn test_token_has_allocations() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let (splitter, splitter_address) =
+        create_splitter_with_default_shares(&env, &Address::generate(&env));
+
+    let (_, sudo_token, token_address_1) = create_token(&env, &Address::generate(&env));
+    let (_, _, token_address_2) = create_token(&env, &Address::generate(&env));
+    splitter.update_whitelisted_tokens(&vec![&env, token_address_1.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000);
+    splitter.distribute_tokens(&token_address_1, &1_000_000);
+
+    // The token is dropped from the list but its allocations are still owed
+    splitter.update_whitelisted_tokens(&vec![&env, token_address_2.clone()]);
+    assert_eq!(
+        splitter.list_tokens_with_allocations(),
+        vec![&env, (token_address_1.clone(), 1_000_000)]
+    );
+    assert_eq!(
+        splitter.try_distribute_tokens(&token_address_1, &1_000_000),
+        Err(Ok(Error::TokenNotWhitelisted))
+    );
 }
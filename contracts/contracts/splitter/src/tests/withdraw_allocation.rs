@@ -1,7 +1,9 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, Env};
+use soroban_sdk::{testutils::Address as _, vec, Address, Bytes, BytesN, Env, String};
+use sorosplits_utils::types::ShareDataKey;
 
 use crate::{
     errors::Error,
+    storage::{distributions::TokenBalanceDataKey, history::LifetimeTotalsDataKey},
     tests::helpers::{
         create_splitter, create_splitter_with_shares, create_token, get_default_share_data,
     },
@@ -39,6 +41,226 @@ fn happy_path() {
     assert_eq!(token.balance(&shareholder), 805_000_000);
 }
 
+#[test]
+fn test_withdraw_dewhitelisted_token() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder_1 = share_data.get(0).unwrap().shareholder;
+    let shareholder_2 = share_data.get(1).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+
+    // Remove the token from the whitelist while the allocations exist
+    splitter.update_whitelisted_tokens(&vec![&env]);
+    assert_eq!(
+        splitter.list_tokens_with_allocations(),
+        vec![&env, (token_address.clone(), 1_000_000_000)]
+    );
+
+    splitter.withdraw_allocation(&token_address, &shareholder_1, &805_000_000);
+    assert_eq!(token.balance(&shareholder_1), 805_000_000);
+    assert_eq!(
+        splitter.list_tokens_with_allocations(),
+        vec![&env, (token_address.clone(), 195_000_000)]
+    );
+
+    splitter.withdraw_allocation(&token_address, &shareholder_2, &195_000_000);
+    assert_eq!(token.balance(&shareholder_2), 195_000_000);
+    assert_eq!(splitter.list_tokens_with_allocations(), vec![&env]);
+}
+
+#[test]
+fn test_prorated_withdrawal() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder_1 = share_data.get(0).unwrap().shareholder;
+    let shareholder_2 = share_data.get(1).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &1_000);
+
+    // Half of the balance is removed outside of the contract
+    token.burn(&splitter_address, &500);
+    assert_eq!(
+        splitter.get_token_balance(&token_address),
+        TokenBalanceDataKey {
+            tracked_balance: 1_000,
+            balance_loss: 0,
+            balance: 500,
+            total_allocation: 1_000,
+            shortfall: 500,
+        }
+    );
+
+    // Both shareholders take the same haircut
+    splitter.withdraw_allocation(&token_address, &shareholder_1, &805);
+    assert_eq!(token.balance(&shareholder_1), 402);
+    assert_eq!(splitter.get_allocation(&shareholder_1, &token_address), 0);
+
+    splitter.withdraw_allocation(&token_address, &shareholder_2, &195);
+    assert_eq!(token.balance(&shareholder_2), 98);
+    assert_eq!(
+        splitter.get_token_balance(&token_address),
+        TokenBalanceDataKey {
+            tracked_balance: 0,
+            balance_loss: 500,
+            balance: 0,
+            total_allocation: 0,
+            shortfall: 0,
+        }
+    );
+    assert_eq!(
+        splitter.get_token_lifetime_totals(&token_address),
+        LifetimeTotalsDataKey {
+            distributed: 1_000,
+            withdrawn: 500,
+            written_off: 500,
+        }
+    );
+}
+
+#[test]
+fn test_prorated_withdrawal_with_reserved_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (token, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000);
+    splitter.distribute_tokens(&token_address, &400);
+    splitter.create_merkle_distribution(
+        &token_address,
+        &600,
+        &BytesN::from_array(&env, &[1; 32]),
+        &100,
+    );
+
+    // The balance covers the allocations but not the reserved amount
+    token.burn(&splitter_address, &500);
+
+    splitter.withdraw_allocation(&token_address, &shareholder, &322);
+    assert_eq!(token.balance(&shareholder), 161);
+}
+
+#[test]
+fn test_token_transfer_unauthorized() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+
+    // Stellar account without a trustline for the token
+    let shareholder = Address::from_string(&String::from_str(
+        &env,
+        "GA7QYNF7SOWQ3GLR2BGMZEHXAVIRZA4KVWLTJJFC7MGXUA74P7UJVSGZ",
+    ));
+    let share_data = vec![
+        &env,
+        ShareDataKey {
+            shareholder: shareholder.clone(),
+            share: 8050,
+        },
+        ShareDataKey {
+            shareholder: Address::generate(&env),
+            share: 1950,
+        },
+    ];
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &805_000_000),
+        Err(Ok(Error::TokenTransferUnauthorized))
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        805_000_000
+    );
+}
+
+#[test]
+fn test_lifetime_totals() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(0).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &2_000_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+    splitter.withdraw_allocation(&token_address, &shareholder, &805_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+    splitter.withdraw_allocation(&token_address, &shareholder, &300_000_000);
+
+    assert_eq!(
+        splitter.get_lifetime_totals(&shareholder, &token_address),
+        LifetimeTotalsDataKey {
+            distributed: 1_610_000_000,
+            withdrawn: 1_105_000_000,
+            written_off: 0,
+        }
+    );
+    assert_eq!(
+        splitter.get_token_lifetime_totals(&token_address),
+        LifetimeTotalsDataKey {
+            distributed: 2_000_000_000,
+            withdrawn: 1_105_000_000,
+            written_off: 0,
+        }
+    );
+}
+
 #[test]
 fn test_not_initialized() {
     let env = Env::default();
@@ -76,6 +298,37 @@ fn test_zero_withdraw_amount() {
     );
 }
 
+#[test]
+fn test_negative_withdraw_amount() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let name = Bytes::from_slice(&env, "Splitter Contract".as_bytes());
+    let share_data = get_default_share_data(&env);
+    let shareholder = share_data.get(1).unwrap().shareholder;
+
+    let (splitter, splitter_address) =
+        create_splitter_with_shares(&env, &admin, &name, &share_data, &true);
+
+    let token_admin = Address::generate(&env);
+    let (_, sudo_token, token_address) = create_token(&env, &token_admin);
+    splitter.update_whitelisted_tokens(&vec![&env, token_address.clone()]);
+
+    sudo_token.mint(&splitter_address, &1_000_000_000);
+    splitter.distribute_tokens(&token_address, &1_000_000_000);
+
+    // A negative amount can't increase the allocation
+    assert_eq!(
+        splitter.try_withdraw_allocation(&token_address, &shareholder, &-1_000_000_000_000_000_000),
+        Err(Ok(Error::ZeroWithdrawalAmount))
+    );
+    assert_eq!(
+        splitter.get_allocation(&shareholder, &token_address),
+        195_000_000
+    );
+}
+
 #[test]
 fn test_withdrawal_amount_above_allocation() {
     let env = Env::default();
@@ -0,0 +1,12 @@
+use soroban_sdk::{Address, BytesN, Env};
+
+/// Returns true if the current contract was deployed by the address with the salt
+///
+/// Contract addresses are derived from the deployer address and the salt, so only
+/// the account that deployed the contract can pass this check.
+pub fn is_deployed_by(e: &Env, deployer: &Address, salt: &BytesN<32>) -> bool {
+    e.deployer()
+        .with_address(deployer.clone(), salt.clone())
+        .deployed_address()
+        == e.current_contract_address()
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+/// Interface of the DEX adapter contracts the Diversifier swaps through
+///
+/// An adapter wraps a single AMM so that the Diversifier can be pointed to
+/// any AMM by changing the adapter address.
+#[contractclient(name = "DexAdapterClient")]
+pub trait DexAdapterInterface {
+    /// Returns the amount of tokens received at the end of the swap path,
+    /// or `None` if any hop of the swap path cannot be swapped
+    fn quote(env: Env, swap_path: Vec<Address>, amount_in: i128) -> Option<i128>;
+
+    /// Swaps `amount_in` of the first token in the swap path and sends the
+    /// received tokens to `to`
+    ///
+    /// The tokens to swap have to be transferred to the adapter before the call.
+    /// Fails if less than `min_amount_out` tokens are received.
+    ///
+    /// Returns the amount of tokens sent to `to`.
+    fn swap(
+        env: Env,
+        swap_path: Vec<Address>,
+        amount_in: i128,
+        min_amount_out: i128,
+        to: Address,
+    ) -> i128;
+}
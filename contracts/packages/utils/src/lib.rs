@@ -1,5 +1,10 @@
 #![no_std]
 
+pub mod deploy;
+pub mod dex;
+pub mod oracle;
 pub mod storage;
+#[cfg(feature = "testutils")]
+pub mod testutils;
 pub mod token;
 pub mod types;
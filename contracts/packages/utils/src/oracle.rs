@@ -0,0 +1,25 @@
+use soroban_sdk::{contractclient, contracttype, Address, Env, Symbol};
+
+/// Asset quoted by a SEP-40 price oracle
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// Price of an asset reported by a SEP-40 price oracle
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PriceData {
+    pub price: i128,
+    /// Ledger timestamp of the price in seconds
+    pub timestamp: u64,
+}
+
+/// Part of the SEP-40 price oracle interface used to read reference prices
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracleInterface {
+    /// Returns the most recent price of the asset, if any
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
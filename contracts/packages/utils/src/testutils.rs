@@ -0,0 +1,124 @@
+//! Soroswap test fixtures shared by the contract tests.
+
+use soroban_sdk::{
+    contract, contractimpl, contracttype, testutils::Address as _,
+    token::StellarAssetClient as TokenAdminClient, xdr::ToXdr, Address, Bytes, BytesN, Env,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub mod soroswap_router {
+    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_router.optimized.wasm",);
+}
+pub mod soroswap_pair {
+    soroban_sdk::contractimport!(file = "../../wasm_external/soroswap_pair.optimized.wasm",);
+}
+
+#[derive(Clone)]
+#[contracttype]
+enum FactoryDataKey {
+    PairWasmHash,
+    Pair(Address, Address),
+}
+
+/// Minimal Soroswap factory that deploys the pairs the router asks for.
+///
+/// The pairs are deployed with the same salt as the Soroswap factory so that
+/// the router can derive their addresses.
+#[contract]
+pub struct MockSoroswapFactory;
+
+#[contractimpl]
+impl MockSoroswapFactory {
+    pub fn initialize(env: Env, pair_wasm_hash: BytesN<32>) {
+        env.storage()
+            .instance()
+            .set(&FactoryDataKey::PairWasmHash, &pair_wasm_hash);
+    }
+
+    pub fn fees_enabled(_env: Env) -> bool {
+        false
+    }
+
+    pub fn fee_to(env: Env) -> Address {
+        env.current_contract_address()
+    }
+
+    pub fn pair_exists(env: Env, token_a: Address, token_b: Address) -> bool {
+        let (token_0, token_1) = sort_tokens(token_a, token_b);
+        env.storage()
+            .instance()
+            .has(&FactoryDataKey::Pair(token_0, token_1))
+    }
+
+    pub fn create_pair(env: Env, token_a: Address, token_b: Address) -> Address {
+        let (token_0, token_1) = sort_tokens(token_a, token_b);
+
+        let mut salt = Bytes::new(&env);
+        salt.append(&token_0.clone().to_xdr(&env));
+        salt.append(&token_1.clone().to_xdr(&env));
+
+        let pair_wasm_hash: BytesN<32> = env
+            .storage()
+            .instance()
+            .get(&FactoryDataKey::PairWasmHash)
+            .unwrap();
+        let pair_address = env
+            .deployer()
+            .with_current_contract(env.crypto().sha256(&salt))
+            .deploy(pair_wasm_hash);
+        soroswap_pair::Client::new(&env, &pair_address).initialize(
+            &env.current_contract_address(),
+            &token_0,
+            &token_1,
+        );
+
+        env.storage()
+            .instance()
+            .set(&FactoryDataKey::Pair(token_0, token_1), &pair_address);
+        pair_address
+    }
+}
+
+fn sort_tokens(token_a: Address, token_b: Address) -> (Address, Address) {
+    if token_a < token_b {
+        (token_a, token_b)
+    } else {
+        (token_b, token_a)
+    }
+}
+
+/// Registers the Soroswap router with a local factory and returns its client
+pub fn create_soroswap_router(e: &Env) -> soroswap_router::Client<'_> {
+    let pair_wasm_hash = e.deployer().upload_contract_wasm(soroswap_pair::WASM);
+    let factory_address = e.register_contract(None, MockSoroswapFactory);
+    MockSoroswapFactoryClient::new(e, &factory_address).initialize(&pair_wasm_hash);
+
+    let router_address = e.register_contract_wasm(None, soroswap_router::WASM);
+    let router = soroswap_router::Client::new(e, &router_address);
+    router.initialize(&factory_address);
+    router
+}
+
+/// Adds liquidity to the pair of the tokens, creating the pair if it doesn't exist
+pub fn add_liquidity(
+    e: &Env,
+    router: &soroswap_router::Client,
+    token_a: &Address,
+    token_b: &Address,
+    amount_a: i128,
+    amount_b: i128,
+) {
+    let provider = Address::generate(e);
+    TokenAdminClient::new(e, token_a).mint(&provider, &amount_a);
+    TokenAdminClient::new(e, token_b).mint(&provider, &amount_b);
+    router.add_liquidity(
+        token_a,
+        token_b,
+        &amount_a,
+        &amount_b,
+        &0,
+        &0,
+        &provider,
+        &u64::MAX,
+    );
+}
@@ -1,15 +1,242 @@
 use soroban_sdk::{
     token::{self, TokenClient},
-    Address, Env,
+    xdr::ToXdr,
+    Address, Bytes, Env, Error as SorobanError, String,
 };
 
+/// Stellar Asset Contract error for a deauthorized balance
+const BALANCE_DEAUTHORIZED_ERROR: u32 = 11;
+/// Stellar Asset Contract error for a missing trustline
+const TRUSTLINE_MISSING_ERROR: u32 = 13;
+
+/// Maximum length of a Stellar Asset Contract name ("CODE:ISSUER")
+const MAX_STELLAR_ASSET_NAME_LENGTH: usize = 12 + 1 + 56;
+/// Length of a strkey encoded account ID ("G...")
+const ACCOUNT_ID_STRKEY_LENGTH: usize = 56;
+/// Version byte of a strkey encoded ed25519 public key
+const ACCOUNT_ID_VERSION_BYTE: u8 = 6 << 3;
+
+// XDR discriminants used to derive the address of a Stellar Asset Contract
+const ENVELOPE_TYPE_CONTRACT_ID: u32 = 8;
+const CONTRACT_ID_PREIMAGE_FROM_ASSET: u32 = 1;
+const ASSET_TYPE_NATIVE: u32 = 0;
+const ASSET_TYPE_CREDIT_ALPHANUM4: u32 = 1;
+const ASSET_TYPE_CREDIT_ALPHANUM12: u32 = 2;
+const PUBLIC_KEY_TYPE_ED25519: u32 = 0;
+const SC_VAL_TYPE_ADDRESS: u32 = 18;
+const SC_ADDRESS_TYPE_CONTRACT: u32 = 1;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind {
+    /// Stellar Asset Contract, including native XLM
+    StellarAsset,
+    /// Custom contract implementing the token interface
+    Custom,
+}
+
+/// Metadata read from the token while validating it
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenMetadata {
+    pub kind: TokenKind,
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenError {
+    /// The contract doesn't return a valid name
+    InvalidName,
+    /// The contract doesn't return valid decimals
+    InvalidDecimals,
+    /// The contract doesn't return a valid symbol
+    InvalidSymbol,
+    /// The contract doesn't return a valid balance
+    InvalidBalance,
+    /// The transfer was rejected because a balance is deauthorized or a trustline is missing
+    TransferUnauthorized,
+    /// The transfer failed for any other reason
+    TransferFailed,
+}
+
 pub fn get_token_client<'a>(env: &'a Env, token_address: &Address) -> TokenClient<'a> {
     token::Client::new(env, token_address)
 }
 
-pub fn check_token(env: &Env, token_address: &Address) {
-    let token_client = get_token_client(env, &token_address);
-    token_client.name();
+/// Validates the token against the token interface without trapping.
+///
+/// Checks that `name`, `decimals`, `symbol` and `balance` can be called on the contract
+/// and return valid values. Returns the metadata of the token if it is valid.
+pub fn validate_token(env: &Env, token_address: &Address) -> Result<TokenMetadata, TokenError> {
+    let token_client = get_token_client(env, token_address);
+
+    let name = match token_client.try_name() {
+        Ok(Ok(name)) => name,
+        _ => return Err(TokenError::InvalidName),
+    };
+    let decimals = match token_client.try_decimals() {
+        Ok(Ok(decimals)) => decimals,
+        _ => return Err(TokenError::InvalidDecimals),
+    };
+    let symbol = match token_client.try_symbol() {
+        Ok(Ok(symbol)) if symbol.len() > 0 => symbol,
+        _ => return Err(TokenError::InvalidSymbol),
+    };
+    match token_client.try_balance(&env.current_contract_address()) {
+        Ok(Ok(balance)) if balance >= 0 => (),
+        _ => return Err(TokenError::InvalidBalance),
+    };
+
+    let kind = if is_stellar_asset_contract(env, token_address, &name) {
+        TokenKind::StellarAsset
+    } else {
+        TokenKind::Custom
+    };
+
+    Ok(TokenMetadata {
+        kind,
+        name,
+        symbol,
+        decimals,
+    })
+}
+
+/// Returns true if the token is the Stellar Asset Contract of the asset in its name.
+///
+/// Stellar Asset Contracts are named "native" or "CODE:ISSUER". Any contract can return
+/// such a name, so the contract address is derived from the asset and compared to the token.
+fn is_stellar_asset_contract(env: &Env, token_address: &Address, name: &String) -> bool {
+    let asset = match get_stellar_asset_xdr(env, name) {
+        Some(asset) => asset,
+        None => return false,
+    };
+
+    let mut preimage = Bytes::new(env);
+    preimage.extend_from_array(&ENVELOPE_TYPE_CONTRACT_ID.to_be_bytes());
+    preimage.append(&env.ledger().network_id().into());
+    preimage.extend_from_array(&CONTRACT_ID_PREIMAGE_FROM_ASSET.to_be_bytes());
+    preimage.append(&asset);
+    let contract_id = env.crypto().sha256(&preimage);
+
+    let mut address_xdr = Bytes::new(env);
+    address_xdr.extend_from_array(&SC_VAL_TYPE_ADDRESS.to_be_bytes());
+    address_xdr.extend_from_array(&SC_ADDRESS_TYPE_CONTRACT.to_be_bytes());
+    address_xdr.append(&contract_id.into());
+
+    token_address.clone().to_xdr(env) == address_xdr
+}
+
+/// Returns the XDR encoded asset of a Stellar Asset Contract name
+fn get_stellar_asset_xdr(env: &Env, name: &String) -> Option<Bytes> {
+    if *name == String::from_str(env, "native") {
+        return Some(Bytes::from_array(env, &ASSET_TYPE_NATIVE.to_be_bytes()));
+    }
+
+    let len = name.len() as usize;
+    if len > MAX_STELLAR_ASSET_NAME_LENGTH {
+        return None;
+    }
+    let mut buf = [0u8; MAX_STELLAR_ASSET_NAME_LENGTH];
+    name.copy_into_slice(&mut buf[..len]);
+    let separator = buf[..len].iter().position(|c| *c == b':')?;
+    let code = &buf[..separator];
+    let issuer = decode_account_id(&buf[separator + 1..len])?;
+
+    let mut asset = Bytes::new(env);
+    match code.len() {
+        1..=4 => {
+            let mut asset_code = [0u8; 4];
+            asset_code[..code.len()].copy_from_slice(code);
+            asset.extend_from_array(&ASSET_TYPE_CREDIT_ALPHANUM4.to_be_bytes());
+            asset.extend_from_array(&asset_code);
+        }
+        5..=12 => {
+            let mut asset_code = [0u8; 12];
+            asset_code[..code.len()].copy_from_slice(code);
+            asset.extend_from_array(&ASSET_TYPE_CREDIT_ALPHANUM12.to_be_bytes());
+            asset.extend_from_array(&asset_code);
+        }
+        _ => return None,
+    }
+    asset.extend_from_array(&PUBLIC_KEY_TYPE_ED25519.to_be_bytes());
+    asset.extend_from_array(&issuer);
+    Some(asset)
+}
+
+/// Decodes a strkey encoded account ID ("G...") into its ed25519 public key
+fn decode_account_id(strkey: &[u8]) -> Option<[u8; 32]> {
+    if strkey.len() != ACCOUNT_ID_STRKEY_LENGTH {
+        return None;
+    }
+
+    // Base32 decode the version byte, the public key and the checksum
+    let mut decoded = [0u8; 35];
+    let mut index = 0;
+    let mut value: u32 = 0;
+    let mut bits = 0;
+    for c in strkey {
+        let digit = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'2'..=b'7' => c - b'2' + 26,
+            _ => return None,
+        };
+        value = (value << 5) | digit as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            decoded[index] = (value >> bits) as u8;
+            value &= (1 << bits) - 1;
+            index += 1;
+        }
+    }
+
+    let checksum = u16::from_le_bytes([decoded[33], decoded[34]]);
+    if decoded[0] != ACCOUNT_ID_VERSION_BYTE || crc16_xmodem(&decoded[..33]) != checksum {
+        return None;
+    }
+
+    let mut public_key = [0u8; 32];
+    public_key.copy_from_slice(&decoded[1..33]);
+    Some(public_key)
+}
+
+/// CRC16-XModem checksum used by the strkey encoding
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Transfers the tokens from the current contract without trapping.
+///
+/// Stellar Asset Contracts reject transfers when the issuer deauthorizes a balance
+/// or when the recipient has no trustline, which is returned as `TransferUnauthorized`.
+pub fn try_transfer(
+    env: &Env,
+    token_address: &Address,
+    to: &Address,
+    amount: &i128,
+) -> Result<(), TokenError> {
+    let token_client = get_token_client(env, token_address);
+    match token_client.try_transfer(&env.current_contract_address(), to, amount) {
+        Ok(Ok(())) => Ok(()),
+        Err(Ok(error))
+            if error == SorobanError::from_contract_error(BALANCE_DEAUTHORIZED_ERROR)
+                || error == SorobanError::from_contract_error(TRUSTLINE_MISSING_ERROR) =>
+        {
+            Err(TokenError::TransferUnauthorized)
+        }
+        _ => Err(TokenError::TransferFailed),
+    }
 }
 
 pub fn get_token_balance(env: &Env, token_address: &Address, owner: &Address) -> i128 {
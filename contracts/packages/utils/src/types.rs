@@ -1,8 +1,26 @@
 use soroban_sdk::{contracttype, Address};
 
+/// Maximum protocol fee in basis points (5%)
+pub const MAX_PROTOCOL_FEE: i128 = 500;
+
 #[derive(Clone, Debug, PartialEq)]
 #[contracttype]
 pub struct ShareDataKey {
     pub shareholder: Address,
     pub share: i128,
 }
+
+#[derive(Clone, Debug, PartialEq)]
+#[contracttype]
+pub struct ProtocolFeeDataKey {
+    /// Address that receives the fee allocations
+    pub recipient: Address,
+    /// Fee taken from each distribution in basis points
+    pub fee: i128,
+}
+impl ProtocolFeeDataKey {
+    /// Returns true if the fee is between 0 and the maximum protocol fee
+    pub fn is_valid(&self) -> bool {
+        (0..=MAX_PROTOCOL_FEE).contains(&self.fee)
+    }
+}
@@ -3,6 +3,9 @@
 SOROBAN_RPC_URL="https://soroban-testnet.stellar.org"
 SOROBAN_NETWORK_PASSPHRASE="Test SDF Network ; September 2015"
 NETWORK="testnet"
+SOROSWAP_ROUTER_CONTRACT_ID="CDGHOS7DDZ7DB24J7TMFDEAIR7LS7GLMT5J5KEZMUF6MSX5BFHCXQIB3"
+# Protocol fee in basis points taken from each distribution (maximum 500)
+PROTOCOL_FEE="${PROTOCOL_FEE:-0}"
 
 echo "1. Addding testnet to soroban config"
 soroban config network add --global testnet \
@@ -16,6 +19,7 @@ soroban config network add --global testnet \
 #   --network "$NETWORK"
 
 export SOROSPLITS_WALLET=$(soroban config identity address sorosplits-wallet)
+export PROTOCOL_FEE_RECIPIENT="${PROTOCOL_FEE_RECIPIENT:-$SOROSPLITS_WALLET}"
 # echo "3. New wallet "sorosplit-wallet" created: $(echo $SOROSPLITS_WALLET) "
 # printf "%s" "$SOROSPLITS_WALLET" > scripts/artifacts/sorosplits_wallet
 
@@ -45,13 +49,53 @@ export DIVERSIFIER_CONTRACT_WASM_HASH=$(soroban contract install \
 printf "%s" "$DIVERSIFIER_CONTRACT_WASM_HASH" > scripts/artifacts/diversifier_contract_wasm_hash
 
 echo "8. Deploying the deployer contract to the network"
+# The deployer can only be initialized by the wallet that deployed it with this salt
+export DEPLOYER_SALT=$(openssl rand -hex 32)
 export DEPLOYER_CONTRACT_ID=$(soroban contract deploy \
   --wasm target/wasm32-unknown-unknown/release/sorosplits_deployer.wasm \
+  --salt "$DEPLOYER_SALT" \
   --source sorosplits-wallet \
   --network testnet)
 printf "%s" "$DEPLOYER_CONTRACT_ID" > scripts/artifacts/deployer_contract_id
 
-echo "9. Deploying the token contract to the network"
+# Initialize the deployer right after it is deployed
+soroban contract invoke \
+--source-account sorosplits-wallet \
+--rpc-url "$SOROBAN_RPC_URL" \
+--network-passphrase "$SOROBAN_NETWORK_PASSPHRASE" \
+--network "$NETWORK" \
+    --id "$DEPLOYER_CONTRACT_ID" \
+    -- \
+    init_deployer \
+    --admin "$SOROSPLITS_WALLET" \
+    --salt "$DEPLOYER_SALT" \
+    --protocol_fee "{\"recipient\":\"$PROTOCOL_FEE_RECIPIENT\",\"fee\":\"$PROTOCOL_FEE\"}" \
+  || { echo "Deployer initialization failed"; exit 1; }
+
+echo "9. Deploying the Soroswap adapter contract to the network"
+# The adapter can only be initialized by the wallet that deployed it with this salt
+export SOROSWAP_ADAPTER_SALT=$(openssl rand -hex 32)
+export SOROSWAP_ADAPTER_CONTRACT_ID=$(soroban contract deploy \
+  --wasm target/wasm32-unknown-unknown/release/sorosplits_soroswap_adapter.wasm \
+  --salt "$SOROSWAP_ADAPTER_SALT" \
+  --source sorosplits-wallet \
+  --network testnet)
+printf "%s" "$SOROSWAP_ADAPTER_CONTRACT_ID" > scripts/artifacts/soroswap_adapter_contract_id
+
+soroban contract invoke \
+--source-account sorosplits-wallet \
+--rpc-url "$SOROBAN_RPC_URL" \
+--network-passphrase "$SOROBAN_NETWORK_PASSPHRASE" \
+--network "$NETWORK" \
+    --id "$SOROSWAP_ADAPTER_CONTRACT_ID" \
+    -- \
+    init_adapter \
+    --deployer "$SOROSPLITS_WALLET" \
+    --salt "$SOROSWAP_ADAPTER_SALT" \
+    --router_address "$SOROSWAP_ROUTER_CONTRACT_ID" \
+  || { echo "Soroswap adapter initialization failed"; exit 1; }
+
+echo "10. Deploying the token contract to the network"
 export TOKEN_CONTRACT_ID=$(soroban contract deploy \
   --wasm wasm_external/soroban_token_contract.wasm \
   --source sorosplits-wallet \
@@ -71,12 +115,13 @@ soroban contract invoke \
 --name "Custom Token" \
 --symbol "CTK"
 
-echo "10. Contract deployment complete. "
+echo "11. Contract deployment complete. "
 echo "Contract details: "
 
 echo "Splitter Contract Wasm Hash: $SPLITTER_CONTRACT_WASM_HASH"
 echo "Diversifier Contract Wasm Hash: $DIVERSIFIER_CONTRACT_WASM_HASH"
 echo "Deployer Contract ID: $DEPLOYER_CONTRACT_ID"
+echo "Soroswap Adapter Contract ID: $SOROSWAP_ADAPTER_CONTRACT_ID"
 echo "Token Contract ID: $TOKEN_CONTRACT_ID"
 
 exit 0
\ No newline at end of file
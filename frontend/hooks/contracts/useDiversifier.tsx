@@ -1,6 +1,11 @@
 import useContracts from "./useContracts"
 import useApiService from "../useApi"
 
+// Maximum allowed slippage of the swaps in basis points
+const SWAP_SLIPPAGE_BPS = 50
+// Number of ledgers the swaps stay valid for (around 10 minutes)
+const SWAP_DEADLINE_LEDGERS = 120
+
 const useDiversifier = () => {
   const { diversifierContract } = useContracts()
   const { contractApiService } = useApiService()
@@ -46,6 +51,21 @@ const useDiversifier = () => {
       throw new Error("Swap path cannot be found for the given tokens")
     }
 
+    const amountOut = await diversifierContract.query({
+      contractId,
+      method: "quote_swap",
+      args: {
+        swapPath,
+        amount,
+      },
+    })
+    const minAmountOut = Number(
+      (BigInt(amountOut) * BigInt(10000 - SWAP_SLIPPAGE_BPS)) / BigInt(10000)
+    )
+    const deadlineLedger =
+      (await diversifierContract.getLatestLedgerSequence()) +
+      SWAP_DEADLINE_LEDGERS
+
     const signature = await diversifierContract.signTransaction([
       diversifierContract.getCallOperation({
         contractId,
@@ -53,6 +73,8 @@ const useDiversifier = () => {
         args: {
           swapPath,
           amount,
+          minAmountOut,
+          deadlineLedger,
         },
       }),
     ])
@@ -152,6 +152,14 @@ const useSplitter = () => {
     })
   }
 
+  const listWhitelistedTokenInfo = async (contractAddress: string) => {
+    return splitterContract.query({
+      contractId: contractAddress,
+      method: "list_whitelisted_token_info",
+      args: {},
+    })
+  }
+
   const getAllocation = async (
     contractAddress: string,
     tokenAddress: string,
@@ -214,6 +222,7 @@ const useSplitter = () => {
       getConfig,
       listShares,
       listWhitelistedTokens,
+      listWhitelistedTokenInfo,
       getAllocation,
       getUnusedTokens,
     },
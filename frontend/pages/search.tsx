@@ -9,6 +9,7 @@ import ShareholdersCard, {
 import {
   ContractConfigResult,
   ShareDataProps,
+  TokenInfoResult,
 } from "sorosplits-sdk/lib/contracts/Splitter"
 import useAppStore from "@/store/index"
 import { loadingToast, successToast, errorToast } from "@/utils/toast"
@@ -63,6 +64,9 @@ const NewSearch: React.FC = () => {
   >([])
   const [contractWhitelistedSwapTokens, setContractWhitelistedSwapTokens] =
     useState<string[][]>([])
+  const [contractTokenInfo, setContractTokenInfo] = useState<
+    TokenInfoResult[]
+  >([])
   const [contractTransactions, setContractTransactions] = useState<
     SplitterContractActivity[]
   >([])
@@ -189,8 +193,12 @@ const NewSearch: React.FC = () => {
         setContractShares(shareData)
         setUpdatedContractShares(shareData)
 
-        const whitelistedTokens = await splitter.query.listWhitelistedTokens(
-          splitterAddress
+        const whitelistedTokens = (
+          await splitter.query.listWhitelistedTokens(splitterAddress)
+        ).map((item) => item.token)
+        // The splitter caches the token metadata when the tokens are whitelisted
+        setContractTokenInfo(
+          await splitter.query.listWhitelistedTokenInfo(splitterAddress)
         )
         setContractWhitelistedTokens(whitelistedTokens)
         setUpdatedContractWhitelistedTokens(whitelistedTokens)
@@ -220,6 +228,7 @@ const NewSearch: React.FC = () => {
     setContractIsDiversifierActive(false)
     setContractWhitelistedTokens([])
     setContractWhitelistedSwapTokens([])
+    setContractTokenInfo([])
     setWhitelistedTokensCardData([])
     setWhitelistedTokensCardDataLoading(true)
     setWhitelistedSwapTokensCardData([])
@@ -284,6 +293,20 @@ const NewSearch: React.FC = () => {
     return tokenBalancesCardData
   }, [whitelistedSwapTokensCardData])
 
+  const getTokenDetails = async (tokenAddress: string) => {
+    const tokenInfo = contractTokenInfo.find(
+      (item) => item.token === tokenAddress
+    )
+    if (tokenInfo) {
+      return {
+        name: tokenInfo.name,
+        symbol: tokenInfo.symbol,
+        decimals: tokenInfo.decimals,
+      }
+    }
+    return token.getTokenDetails(tokenAddress)
+  }
+
   useEffect(() => {
     const fetch = async () => {
       if (contractAddress === "" || contractWhitelistedTokens.length === 0) {
@@ -295,7 +318,7 @@ const NewSearch: React.FC = () => {
 
       const tokenDataPromises = contractWhitelistedTokens.map(
         async (tokenAddress) => {
-          const details = await token.getTokenDetails(tokenAddress)
+          const details = await getTokenDetails(tokenAddress)
           return {
             address: tokenAddress,
             name: details.name,
@@ -330,7 +353,7 @@ const NewSearch: React.FC = () => {
         async (item) => {
           let response = []
           for (let tokenAddress of item) {
-            const details = await token.getTokenDetails(tokenAddress)
+            const details = await getTokenDetails(tokenAddress)
             response.push({
               ...details,
               address: tokenAddress,
@@ -9,6 +9,8 @@ interface Config {
   splitterWasmHash: string
   diversifierWasmHash: string
   sorobanDomainsContractId: string
+  soroswapRouterContractId: string
+  soroswapAdapterContractId: string
 }
 
 interface ConfigExport {
@@ -28,10 +30,24 @@ const TESNET_CONFIG: Config = {
     "CBXZ7HLC4XMNXAU6GNRLMP3U3ZQUFLSZ7RH2UE6SIAZQ7XIA7J6FTCW3",
   sorobanDomainsContractId:
     "CAPXARBAGOJO3HBPWTISP5JB35DWOX6GYWBZMIPH6A2XZIMISDA3762L",
+  soroswapRouterContractId:
+    "CDGHOS7DDZ7DB24J7TMFDEAIR7LS7GLMT5J5KEZMUF6MSX5BFHCXQIB3",
+  // Soroswap adapter deployed by contracts/scripts/deploy.sh for the router above
+  soroswapAdapterContractId: "",
 }
 
 const CONFIG: ConfigExport = {
   testnet: TESNET_CONFIG,
 }
 
+export const getSoroswapAdapterContractId = (network: Network): string => {
+  const contractId = CONFIG[network].soroswapAdapterContractId
+  if (!contractId) {
+    throw new Error(
+      `soroswapAdapterContractId is not set for ${network}, deploy the adapter with contracts/scripts/deploy.sh and update the SDK config`
+    )
+  }
+  return contractId
+}
+
 export default CONFIG
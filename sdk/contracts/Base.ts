@@ -34,6 +34,12 @@ export default class BaseContract {
     })
   }
 
+  public async getLatestLedgerSequence(): Promise<number> {
+    const server = this.getServer()
+    const { sequence } = await server.getLatestLedger()
+    return sequence
+  }
+
   public async sendTransaction(
     signedTx: string
   ): Promise<SorobanRpc.Api.SendTransactionResponse> {
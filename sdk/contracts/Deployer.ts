@@ -7,7 +7,7 @@ import {
   scValToNative,
   xdr,
 } from "@stellar/stellar-sdk"
-import CONFIG, { Network } from "../config"
+import CONFIG, { Network, getSoroswapAdapterContractId } from "../config"
 import BaseContract from "./Base"
 import { ShareDataProps } from "./Splitter"
 import { randomBytes } from "../utils/randomBytes"
@@ -142,6 +142,9 @@ export class DeployerContract extends BaseContract {
       nativeToScVal(Buffer.from(randomBytes()), { type: "bytes" }),
       xdr.ScVal.scvBool(isDiversifierActive),
       xdr.ScVal.scvVec(splitterInitArgs),
+      nativeToScVal(getSoroswapAdapterContractId(this.network), {
+        type: "address",
+      }),
     ]
 
     let diversifierDeployerArgs = [
@@ -229,6 +232,10 @@ export class DeployerContract extends BaseContract {
           ),
         }),
       ]),
+      // DEX adapter
+      nativeToScVal(getSoroswapAdapterContractId(this.network), {
+        type: "address",
+      }),
       // Args
       xdr.ScVal.scvVec(
         args.map((arg) => {
@@ -374,7 +381,7 @@ export class DeployerContract extends BaseContract {
 
   private decodeDeployNetworkParams(args: xdr.ScVal[]) {
     const admin = scValToNative(args[0])
-    const networkArgs = scValToNative(args[2])
+    const networkArgs = scValToNative(args[3])
     const networkData = []
     for (let item of networkArgs) {
       networkData.push({
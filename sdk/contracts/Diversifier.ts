@@ -9,14 +9,24 @@ import {
   SorobanRpc,
 } from "@stellar/stellar-sdk"
 import { ShareDataProps } from "./Splitter"
-import { Network } from "../config"
+import CONFIG, { Network, getSoroswapAdapterContractId } from "../config"
 import ba from "../utils/binascii"
 import BaseContract from "./Base"
 
 export type CallMethod =
   | "init_diversifier"
   | "update_whitelisted_swap_tokens"
+  | "update_intermediate_tokens"
+  | "update_price_oracle"
   | "swap_and_distribute_tokens"
+  | "swap_and_distribute_best"
+  | "swap_to_pending"
+  | "swap_to_splitter"
+  | "settle"
+  | "update_basket"
+  | "diversify_basket"
+  | "update_preferred_token"
+  | "distribute_with_preferences"
   | "toggle_diversifier"
 
 export type MethodArgs<T extends CallMethod> = T extends "init_diversifier"
@@ -29,8 +39,52 @@ export type MethodArgs<T extends CallMethod> = T extends "init_diversifier"
     }
   : T extends "update_whitelisted_swap_tokens"
   ? { tokenAddress: string; swapTokens: string[] }
+  : T extends "update_intermediate_tokens"
+  ? { tokens: string[] }
+  : T extends "update_price_oracle"
+  ? { priceOracle: PriceOracleProps | null }
+  : T extends "swap_and_distribute_best"
+  ? {
+      tokenIn: string
+      tokenOut: string
+      amount: number
+      minAmountOut: number
+      deadlineLedger: number
+    }
+  : T extends "swap_to_pending" | "swap_to_splitter"
+  ? {
+      swapPath: string[]
+      amount: number
+      minAmountOut: number
+      deadlineLedger: number
+    }
+  : T extends "settle"
+  ? { tokenAddress: string }
+  : T extends "update_basket"
+  ? { tokenAddress: string; targets: BasketTargetProps[] }
+  : T extends "diversify_basket"
+  ? {
+      tokenAddress: string
+      amount: number
+      minAmountsOut: number[]
+      deadlineLedger: number
+    }
+  : T extends "update_preferred_token"
+  ? { shareholder: string; tokenAddress: string | null }
+  : T extends "distribute_with_preferences"
+  ? {
+      tokenAddress: string
+      amount: number
+      minAmountsOut: { tokenAddress: string; amount: number }[]
+      deadlineLedger: number
+    }
   : T extends "swap_and_distribute_tokens"
-  ? { swapPath: string[]; amount: number }
+  ? {
+      swapPath: string[]
+      amount: number
+      minAmountOut: number
+      deadlineLedger: number
+    }
   : T extends "toggle_diversifier"
   ? {}
   : never
@@ -44,12 +98,33 @@ export interface CallContractArgs<T extends CallMethod> {
 export type QueryMethod =
   | "get_diversifier_config"
   | "list_whitelisted_swap_tokens"
+  | "quote_swap"
+  | "quote_best_route"
+  | "list_intermediate_tokens"
+  | "get_basket"
+  | "get_preferred_token"
+  | "get_price_oracle"
+  | "get_pending_amount"
 
 export type QueryArgs<T extends QueryMethod> =
   T extends "get_diversifier_config"
     ? {}
     : T extends "list_whitelisted_swap_tokens"
     ? { tokenAddress: string }
+    : T extends "quote_swap"
+    ? { swapPath: string[]; amount: number }
+    : T extends "quote_best_route"
+    ? { tokenIn: string; tokenOut: string; amount: number }
+    : T extends "list_intermediate_tokens"
+    ? {}
+    : T extends "get_basket"
+    ? { tokenAddress: string }
+    : T extends "get_preferred_token"
+    ? { shareholder: string }
+    : T extends "get_price_oracle"
+    ? {}
+    : T extends "get_pending_amount"
+    ? { tokenAddress: string }
     : never
 
 export interface QueryContractArgs<T extends QueryMethod> {
@@ -63,12 +138,44 @@ export type QueryContractResult<T extends QueryMethod> =
     ? ContractConfigResult
     : T extends "list_whitelisted_swap_tokens"
     ? string[]
+    : T extends "quote_swap"
+    ? bigint
+    : T extends "quote_best_route"
+    ? [string[], bigint]
+    : T extends "list_intermediate_tokens"
+    ? string[]
+    : T extends "get_basket"
+    ? BasketTargetProps[]
+    : T extends "get_preferred_token"
+    ? string | undefined
+    : T extends "get_price_oracle"
+    ? PriceOracleResult | undefined
+    : T extends "get_pending_amount"
+    ? bigint
     : never
 
+export interface BasketTargetProps {
+  token: string
+  weight: number
+}
+
+export interface PriceOracleProps {
+  oracleAddress: string
+  maxDeviation: number
+  maxPriceAge: number
+}
+
+export interface PriceOracleResult {
+  oracle_address: string
+  max_deviation: bigint
+  max_price_age: bigint
+}
+
 export interface ContractConfigResult {
   admin: string
   splitter_address: string
   diversifier_active: boolean
+  dex_adapter_address: string
 }
 
 export interface DecodeArgs {
@@ -123,6 +230,9 @@ export class DiversifierContract extends BaseContract {
               ),
               xdr.ScVal.scvBool(initArgs.splitterInitArgs[2]),
             ]),
+            new Address(getSoroswapAdapterContractId(this.network)).toScVal(),
+            // Contracts initialized directly have no protocol fee
+            xdr.ScVal.scvVoid(),
           ]
         )
         break
@@ -153,6 +263,162 @@ export class DiversifierContract extends BaseContract {
               })
             ),
             nativeToScVal(swapAndDistributeArgs.amount, { type: "i128" }),
+            nativeToScVal(swapAndDistributeArgs.minAmountOut, {
+              type: "i128",
+            }),
+            nativeToScVal(swapAndDistributeArgs.deadlineLedger, {
+              type: "u32",
+            }),
+          ]
+        )
+        break
+      case "update_intermediate_tokens":
+        let intermediateTokensArgs =
+          args as MethodArgs<"update_intermediate_tokens">
+        operation = contract.call(
+          method,
+          ...[
+            xdr.ScVal.scvVec(
+              intermediateTokensArgs.tokens.map((token) => {
+                return new Address(token).toScVal()
+              })
+            ),
+          ]
+        )
+        break
+      case "update_price_oracle":
+        let priceOracle = (args as MethodArgs<"update_price_oracle">)
+          .priceOracle
+        operation = contract.call(
+          method,
+          ...[
+            priceOracle
+              ? xdr.ScVal.scvMap([
+                  new xdr.ScMapEntry({
+                    key: xdr.ScVal.scvSymbol("max_deviation"),
+                    val: nativeToScVal(priceOracle.maxDeviation, {
+                      type: "i128",
+                    }),
+                  }),
+                  new xdr.ScMapEntry({
+                    key: xdr.ScVal.scvSymbol("max_price_age"),
+                    val: nativeToScVal(priceOracle.maxPriceAge, {
+                      type: "u64",
+                    }),
+                  }),
+                  new xdr.ScMapEntry({
+                    key: xdr.ScVal.scvSymbol("oracle_address"),
+                    val: new Address(priceOracle.oracleAddress).toScVal(),
+                  }),
+                ])
+              : xdr.ScVal.scvVoid(),
+          ]
+        )
+        break
+      case "swap_and_distribute_best":
+        let swapBestArgs = args as MethodArgs<"swap_and_distribute_best">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(swapBestArgs.tokenIn).toScVal(),
+            new Address(swapBestArgs.tokenOut).toScVal(),
+            nativeToScVal(swapBestArgs.amount, { type: "i128" }),
+            nativeToScVal(swapBestArgs.minAmountOut, { type: "i128" }),
+            nativeToScVal(swapBestArgs.deadlineLedger, { type: "u32" }),
+          ]
+        )
+        break
+      case "swap_to_pending":
+      case "swap_to_splitter":
+        let swapOnlyArgs = args as MethodArgs<"swap_to_pending">
+        operation = contract.call(
+          method,
+          ...[
+            xdr.ScVal.scvVec(
+              swapOnlyArgs.swapPath.map((address) => {
+                return new Address(address).toScVal()
+              })
+            ),
+            nativeToScVal(swapOnlyArgs.amount, { type: "i128" }),
+            nativeToScVal(swapOnlyArgs.minAmountOut, { type: "i128" }),
+            nativeToScVal(swapOnlyArgs.deadlineLedger, { type: "u32" }),
+          ]
+        )
+        break
+      case "settle":
+        let settleArgs = args as MethodArgs<"settle">
+        operation = contract.call(
+          method,
+          ...[new Address(settleArgs.tokenAddress).toScVal()]
+        )
+        break
+      case "update_basket":
+        let updateBasketArgs = args as MethodArgs<"update_basket">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(updateBasketArgs.tokenAddress).toScVal(),
+            xdr.ScVal.scvVec(
+              updateBasketArgs.targets.map((item) => {
+                return xdr.ScVal.scvMap([
+                  new xdr.ScMapEntry({
+                    key: xdr.ScVal.scvSymbol("token"),
+                    val: new Address(item.token).toScVal(),
+                  }),
+                  new xdr.ScMapEntry({
+                    key: xdr.ScVal.scvSymbol("weight"),
+                    val: nativeToScVal(item.weight, { type: "i128" }),
+                  }),
+                ])
+              })
+            ),
+          ]
+        )
+        break
+      case "diversify_basket":
+        let diversifyBasketArgs = args as MethodArgs<"diversify_basket">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(diversifyBasketArgs.tokenAddress).toScVal(),
+            nativeToScVal(diversifyBasketArgs.amount, { type: "i128" }),
+            xdr.ScVal.scvVec(
+              diversifyBasketArgs.minAmountsOut.map((item) => {
+                return nativeToScVal(item, { type: "i128" })
+              })
+            ),
+            nativeToScVal(diversifyBasketArgs.deadlineLedger, { type: "u32" }),
+          ]
+        )
+        break
+      case "update_preferred_token":
+        let preferredTokenArgs = args as MethodArgs<"update_preferred_token">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(preferredTokenArgs.shareholder).toScVal(),
+            preferredTokenArgs.tokenAddress
+              ? new Address(preferredTokenArgs.tokenAddress).toScVal()
+              : xdr.ScVal.scvVoid(),
+          ]
+        )
+        break
+      case "distribute_with_preferences":
+        let preferencesArgs = args as MethodArgs<"distribute_with_preferences">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(preferencesArgs.tokenAddress).toScVal(),
+            nativeToScVal(preferencesArgs.amount, { type: "i128" }),
+            xdr.ScVal.scvMap(
+              preferencesArgs.minAmountsOut.map((item) => {
+                return new xdr.ScMapEntry({
+                  key: new Address(item.tokenAddress).toScVal(),
+                  val: nativeToScVal(item.amount, { type: "i128" }),
+                })
+              })
+            ),
+            nativeToScVal(preferencesArgs.deadlineLedger, { type: "u32" }),
           ]
         )
         break
@@ -196,6 +462,58 @@ export class DiversifierContract extends BaseContract {
           ...[new Address(whitelistedSwapTokensArgs.tokenAddress).toScVal()]
         )
         break
+      case "quote_swap":
+        let quoteSwapArgs = args as QueryArgs<"quote_swap">
+        operation = contract.call(
+          method,
+          ...[
+            xdr.ScVal.scvVec(
+              quoteSwapArgs.swapPath.map((address) => {
+                return new Address(address).toScVal()
+              })
+            ),
+            nativeToScVal(quoteSwapArgs.amount, { type: "i128" }),
+          ]
+        )
+        break
+      case "quote_best_route":
+        let quoteBestRouteArgs = args as QueryArgs<"quote_best_route">
+        operation = contract.call(
+          method,
+          ...[
+            new Address(quoteBestRouteArgs.tokenIn).toScVal(),
+            new Address(quoteBestRouteArgs.tokenOut).toScVal(),
+            nativeToScVal(quoteBestRouteArgs.amount, { type: "i128" }),
+          ]
+        )
+        break
+      case "list_intermediate_tokens":
+        operation = contract.call(method)
+        break
+      case "get_preferred_token":
+        let getPreferredTokenArgs = args as QueryArgs<"get_preferred_token">
+        operation = contract.call(
+          method,
+          ...[new Address(getPreferredTokenArgs.shareholder).toScVal()]
+        )
+        break
+      case "get_price_oracle":
+        operation = contract.call(method)
+        break
+      case "get_pending_amount":
+        let getPendingAmountArgs = args as QueryArgs<"get_pending_amount">
+        operation = contract.call(
+          method,
+          ...[new Address(getPendingAmountArgs.tokenAddress).toScVal()]
+        )
+        break
+      case "get_basket":
+        let getBasketArgs = args as QueryArgs<"get_basket">
+        operation = contract.call(
+          method,
+          ...[new Address(getBasketArgs.tokenAddress).toScVal()]
+        )
+        break
       default:
         throw new Error("Invalid query method")
     }
@@ -214,14 +532,115 @@ export class DiversifierContract extends BaseContract {
     }
   }
 
+  private decodeUpdateIntermediateTokensParams(args: xdr.ScVal[]) {
+    const tokens = scValToNative(args[0])
+    return {
+      tokens: tokens.map((item: any) => {
+        return item.toString()
+      }),
+    }
+  }
+
+  private decodeUpdatePriceOracleParams(args: xdr.ScVal[]) {
+    const priceOracle = scValToNative(args[0])
+    return {
+      priceOracle: priceOracle
+        ? {
+            oracleAddress: priceOracle.oracle_address.toString(),
+            maxDeviation: Number(BigInt(priceOracle.max_deviation)),
+            maxPriceAge: Number(BigInt(priceOracle.max_price_age)),
+          }
+        : null,
+    }
+  }
+
+  private decodeSwapAndDistributeBestParams(args: xdr.ScVal[]) {
+    const tokenIn = scValToNative(args[0])
+    const tokenOut = scValToNative(args[1])
+    const amount = scValToNative(args[2])
+    const minAmountOut = scValToNative(args[3])
+    const deadlineLedger = scValToNative(args[4])
+    return {
+      tokenIn: tokenIn.toString(),
+      tokenOut: tokenOut.toString(),
+      amount: Number(BigInt(amount)),
+      minAmountOut: Number(BigInt(minAmountOut)),
+      deadlineLedger: Number(deadlineLedger),
+    }
+  }
+
+  private decodeSettleParams(args: xdr.ScVal[]) {
+    const tokenAddress = scValToNative(args[0])
+    return {
+      tokenAddress: tokenAddress.toString(),
+    }
+  }
+
+  private decodeUpdateBasketParams(args: xdr.ScVal[]) {
+    const tokenAddress = scValToNative(args[0])
+    const targets = scValToNative(args[1])
+    return {
+      tokenAddress: tokenAddress.toString(),
+      targets: targets.map((item: any) => {
+        return {
+          token: item.token.toString(),
+          weight: Number(BigInt(item.weight)),
+        }
+      }),
+    }
+  }
+
+  private decodeDiversifyBasketParams(args: xdr.ScVal[]) {
+    const tokenAddress = scValToNative(args[0])
+    const amount = scValToNative(args[1])
+    const minAmountsOut = scValToNative(args[2])
+    const deadlineLedger = scValToNative(args[3])
+    return {
+      tokenAddress: tokenAddress.toString(),
+      amount: Number(BigInt(amount)),
+      minAmountsOut: minAmountsOut.map((item: any) => {
+        return Number(BigInt(item))
+      }),
+      deadlineLedger: Number(deadlineLedger),
+    }
+  }
+
+  private decodeUpdatePreferredTokenParams(args: xdr.ScVal[]) {
+    const shareholder = scValToNative(args[0])
+    const tokenAddress = scValToNative(args[1])
+    return {
+      shareholder: shareholder.toString(),
+      tokenAddress: tokenAddress ? tokenAddress.toString() : null,
+    }
+  }
+
+  private decodeDistributeWithPreferencesParams(args: xdr.ScVal[]) {
+    const tokenAddress = scValToNative(args[0])
+    const amount = scValToNative(args[1])
+    const minAmountsOut = scValToNative(args[2])
+    const deadlineLedger = scValToNative(args[3])
+    return {
+      tokenAddress: tokenAddress.toString(),
+      amount: Number(BigInt(amount)),
+      minAmountsOut: Object.entries(minAmountsOut).map(([key, value]) => {
+        return { tokenAddress: key, amount: Number(BigInt(value as bigint)) }
+      }),
+      deadlineLedger: Number(deadlineLedger),
+    }
+  }
+
   private decodeSwapAndDistributeTokensParams(args: xdr.ScVal[]) {
     const swapPaths = scValToNative(args[0])
     const amount = scValToNative(args[1])
+    const minAmountOut = scValToNative(args[2])
+    const deadlineLedger = scValToNative(args[3])
     return {
       swapPaths: swapPaths.map((item: any) => {
         return item.toString()
       }),
       amount: Number(BigInt(amount)),
+      minAmountOut: Number(BigInt(minAmountOut)),
+      deadlineLedger: Number(deadlineLedger),
     }
   }
 
@@ -254,9 +673,37 @@ export class DiversifierContract extends BaseContract {
       case "update_whitelisted_swap_tokens":
         response.args = this.decodeUpdateWhitelistedSwapTokensParams(args)
         break
+      case "update_intermediate_tokens":
+        response.args = this.decodeUpdateIntermediateTokensParams(args)
+        break
+      case "update_price_oracle":
+        response.args = this.decodeUpdatePriceOracleParams(args)
+        break
       case "swap_and_distribute_tokens":
         response.args = this.decodeSwapAndDistributeTokensParams(args)
         break
+      case "swap_and_distribute_best":
+        response.args = this.decodeSwapAndDistributeBestParams(args)
+        break
+      case "swap_to_pending":
+      case "swap_to_splitter":
+        response.args = this.decodeSwapAndDistributeTokensParams(args)
+        break
+      case "settle":
+        response.args = this.decodeSettleParams(args)
+        break
+      case "update_basket":
+        response.args = this.decodeUpdateBasketParams(args)
+        break
+      case "diversify_basket":
+        response.args = this.decodeDiversifyBasketParams(args)
+        break
+      case "update_preferred_token":
+        response.args = this.decodeUpdatePreferredTokenParams(args)
+        break
+      case "distribute_with_preferences":
+        response.args = this.decodeDistributeWithPreferencesParams(args)
+        break
       case "toggle_diversifier":
         break
       default:
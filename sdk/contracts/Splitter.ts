@@ -60,6 +60,7 @@ export type QueryMethod =
   | "get_config"
   | "get_allocation"
   | "list_whitelisted_tokens"
+  | "list_whitelisted_token_info"
   | "get_unused_tokens"
 
 export interface QueryContractArgs<T extends QueryMethod> {
@@ -76,14 +77,35 @@ export type QueryArgs<T extends QueryMethod> = T extends "list_shares"
   ? { shareholderAddress: string; tokenAddress: string }
   : T extends "list_whitelisted_tokens"
   ? {}
+  : T extends "list_whitelisted_token_info"
+  ? {}
   : T extends "get_unused_tokens"
   ? { tokenAddress: string }
   : never
 
+export interface ProtocolFeeResult {
+  recipient: string
+  fee: BigInt
+}
+
 export interface ContractConfigResult {
   admin: string
   name: Uint8Array
   updatable: boolean
+  protocol_fee: ["Disabled"] | ["Enabled", ProtocolFeeResult]
+}
+
+export interface WhitelistedTokenResult {
+  token: string
+  min_distribution: BigInt
+}
+
+export interface TokenInfoResult {
+  token: string
+  name: string
+  symbol: string
+  decimals: number
+  is_stellar_asset: boolean
 }
 
 export type QueryContractResult<T extends QueryMethod> = T extends "get_config"
@@ -93,7 +115,9 @@ export type QueryContractResult<T extends QueryMethod> = T extends "get_config"
   : T extends "get_allocation"
   ? BigInt
   : T extends "list_whitelisted_tokens"
-  ? string[]
+  ? WhitelistedTokenResult[]
+  : T extends "list_whitelisted_token_info"
+  ? TokenInfoResult[]
   : T extends "get_unused_tokens"
   ? BigInt
   : never
@@ -188,6 +212,8 @@ export class SplitterContract extends BaseContract {
               })
             ),
             xdr.ScVal.scvBool(initArgs.updatable),
+            // Contracts initialized directly have no protocol fee
+            xdr.ScVal.scvVoid(),
           ]
         )
         break
@@ -314,6 +340,9 @@ export class SplitterContract extends BaseContract {
       case "list_whitelisted_tokens":
         operation = contract.call(method)
         break
+      case "list_whitelisted_token_info":
+        operation = contract.call(method)
+        break
       case "get_unused_tokens":
         let getUnusedTokensArgs = args as QueryArgs<"get_unused_tokens">
         operation = contract.call(